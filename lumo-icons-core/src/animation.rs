@@ -0,0 +1,77 @@
+//! Built-in CSS animations for icons.
+
+/// A looping animation for an icon, such as a spinner for loading states.
+///
+/// The animations are defined in [`ANIMATION_STYLESHEET`], which the framework
+/// components add to the page the first time an animated icon renders. Pages
+/// built from [`render_svg`](crate::render_svg) output need to include it
+/// themselves.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum IconAnimation {
+    /// No animation.
+    #[default]
+    None,
+    /// A continuous clockwise rotation.
+    Spin,
+    /// A gentle grow-and-fade, for drawing attention.
+    Pulse,
+    /// A vertical hop.
+    Bounce,
+}
+
+impl IconAnimation {
+    /// The class that applies the animation.
+    pub const fn class(self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            Self::Spin => Some("lumo-icon-spin"),
+            Self::Pulse => Some("lumo-icon-pulse"),
+            Self::Bounce => Some("lumo-icon-bounce"),
+        }
+    }
+}
+
+/// The `id` of the `<style>` element the components inject
+/// [`ANIMATION_STYLESHEET`] in, so it is only added once.
+pub const ANIMATION_STYLESHEET_ID: &str = "lumo-icon-animations";
+
+/// The keyframes and classes behind [`IconAnimation`].
+///
+/// The speed and number of iterations come from the
+/// `--lumo-icon-animation-speed` and `--lumo-icon-animation-iterations` custom
+/// properties, and all animations stop for users who prefer reduced motion.
+pub const ANIMATION_STYLESHEET: &str = "\
+.lumo-icon-spin, .lumo-icon-pulse, .lumo-icon-bounce {
+  transform-origin: center;
+  animation-iteration-count: var(--lumo-icon-animation-iterations, infinite);
+}
+.lumo-icon-spin {
+  animation-name: lumo-icon-spin;
+  animation-duration: calc(2s / var(--lumo-icon-animation-speed, 1));
+  animation-timing-function: linear;
+}
+.lumo-icon-pulse {
+  animation-name: lumo-icon-pulse;
+  animation-duration: calc(2s / var(--lumo-icon-animation-speed, 1));
+  animation-timing-function: ease-in-out;
+}
+.lumo-icon-bounce {
+  animation-name: lumo-icon-bounce;
+  animation-duration: calc(1s / var(--lumo-icon-animation-speed, 1));
+}
+@keyframes lumo-icon-spin {
+  from { transform: rotate(0deg); }
+  to { transform: rotate(360deg); }
+}
+@keyframes lumo-icon-pulse {
+  0%, 100% { transform: scale(1); opacity: 1; }
+  50% { transform: scale(1.15); opacity: 0.6; }
+}
+@keyframes lumo-icon-bounce {
+  0%, 100% { transform: translateY(0); animation-timing-function: cubic-bezier(0.8, 0, 1, 1); }
+  50% { transform: translateY(-25%); animation-timing-function: cubic-bezier(0, 0, 0.2, 1); }
+}
+@media (prefers-reduced-motion: reduce) {
+  .lumo-icon-spin, .lumo-icon-pulse, .lumo-icon-bounce { animation: none; }
+}
+";
@@ -0,0 +1,47 @@
+//! Choosing a style to render when an icon isn't drawn in the requested one.
+
+use crate::{IconStyle, IconStyleData, ViewBox};
+
+/// What to render when an icon isn't drawn in the requested style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StyleFallback {
+    /// Render the empty style, leaving a blank space.
+    None,
+    /// Use the available style closest to the requested one in [`IconStyle::ALL`],
+    /// preferring the earlier one on a tie.
+    #[default]
+    Nearest,
+    /// Use the first available style out of the given ones.
+    Order(&'static [IconStyle]),
+}
+
+impl IconStyleData {
+    /// The style that is rendered when `style` is requested, or `None` if the
+    /// fallback policy doesn't lead to an available style.
+    pub fn resolve_style(&self, style: IconStyle, fallback: StyleFallback) -> Option<IconStyle> {
+        if self.has_style(style) {
+            return Some(style);
+        }
+
+        match fallback {
+            StyleFallback::None => None,
+            StyleFallback::Nearest => {
+                let requested = style as usize;
+                self.available_styles()
+                    .min_by_key(|&s| (s as usize).abs_diff(requested))
+            }
+            StyleFallback::Order(order) => order.iter().copied().find(|&s| self.has_style(s)),
+        }
+    }
+
+    /// The markup for `style`, or for its fallback if the icon isn't drawn in it.
+    pub fn get_or_fallback(&self, style: IconStyle, fallback: StyleFallback) -> &'static str {
+        self.resolve_style(style, fallback)
+            .map_or("", |style| self.get(style))
+    }
+
+    /// The `viewBox` of the style that is rendered when `style` is requested.
+    pub fn view_box_or_fallback(&self, style: IconStyle, fallback: StyleFallback) -> ViewBox {
+        self.view_box(self.resolve_style(style, fallback).unwrap_or(style))
+    }
+}
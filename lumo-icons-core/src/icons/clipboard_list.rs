@@ -0,0 +1,14 @@
+#![doc = r" GENERATED FILE"]
+pub const CLIPBOARD_LIST_META: &crate::IconMeta = &crate::IconMeta {
+    name: "clipboard-list",
+    pascal_name: "ClipboardList",
+    sets: &["core", "ui", "business-finance"],
+    styles: &["ui"],
+    weight: Some("regular"),
+    categories: &["ui", "business", "productivity"],
+    tags: &["list", "note", "paper", "clipboard", "task"],
+    codepoint: Some(71003u32),
+    published_in: Some("1.0"),
+    updated_in: Some("1.0"),
+};
+pub const CLIPBOARD_LIST : & crate :: IconStyleData = & crate :: IconStyleData { markup : ["" , "" , "" , "" , "" , "<path d=\"M5.25 2C3.731 2 2.5 3.231 2.5 4.75v9.5C2.5 15.769 3.731 17 5.25 17h7.5c1.519 0 2.75-1.231 2.75-2.75V4.75C15.5 3.231 14.269 2 12.75 2H5.25z\" style=\"fill: var(--lumo-icon-secondary-color, currentColor); fill-opacity: var(--lumo-icon-secondary-opacity, 0.4)\"></path><path fill-rule=\"evenodd\" clip-rule=\"evenodd\" d=\"M5.5 2.25C5.5 1.284 6.284.5 7.25.5h3.5c.966 0 1.75.784 1.75 1.75 0 .966-.784 1.75-1.75 1.75H7.25C6.284 4 5.5 3.216 5.5 2.25z\"></path><path fill-rule=\"evenodd\" clip-rule=\"evenodd\" d=\"M5.5 11.75c0-.414.336-.75.75-.75h2.5c.414 0 .75.336.75.75 0 .414-.336.75-.75.75H6.25c-.414 0-.75-.336-.75-.75z\"></path><path fill-rule=\"evenodd\" clip-rule=\"evenodd\" d=\"M5.5 8.75c0-.414.336-.75.75-.75h2.5c.414 0 .75.336.75.75 0 .414-.336.75-.75.75H6.25c-.414 0-.75-.336-.75-.75z\"></path><path fill-rule=\"evenodd\" clip-rule=\"evenodd\" d=\"M10.5 11.75c0-.414.336-.75.75-.75h.5c.414 0 .75.336.75.75 0 .414-.336.75-.75.75h-.5c-.414 0-.75-.336-.75-.75z\"></path><path fill-rule=\"evenodd\" clip-rule=\"evenodd\" d=\"M10.5 8.75c0-.414.336-.75.75-.75h.5c.414 0 .75.336.75.75 0 .414-.336.75-.75.75h-.5c-.414 0-.75-.336-.75-.75z\"></path>"] , view_boxes : [crate :: ViewBox :: new (0f32 , 0f32 , 18f32 , 18f32) , crate :: ViewBox :: new (0f32 , 0f32 , 18f32 , 18f32) , crate :: ViewBox :: new (0f32 , 0f32 , 18f32 , 18f32) , crate :: ViewBox :: new (0f32 , 0f32 , 18f32 , 18f32) , crate :: ViewBox :: new (0f32 , 0f32 , 18f32 , 18f32) , crate :: ViewBox :: new (0f32 , 0f32 , 18f32 , 18f32)] , meta : CLIPBOARD_LIST_META , # [cfg (feature = "nodes")] nodes : [& [] , & [] , & [] , & [] , & [] , & [crate :: SvgNode :: Element { name : "path" , attributes : & [("d" , "M5.25 2C3.731 2 2.5 3.231 2.5 4.75v9.5C2.5 15.769 3.731 17 5.25 17h7.5c1.519 0 2.75-1.231 2.75-2.75V4.75C15.5 3.231 14.269 2 12.75 2H5.25z") , ("style" , "fill: var(--lumo-icon-secondary-color, currentColor); fill-opacity: var(--lumo-icon-secondary-opacity, 0.4)")] , children : & [] , } , crate :: SvgNode :: Element { name : "path" , attributes : & [("fill-rule" , "evenodd") , ("clip-rule" , "evenodd") , ("d" , "M5.5 2.25C5.5 1.284 6.284.5 7.25.5h3.5c.966 0 1.75.784 1.75 1.75 0 .966-.784 1.75-1.75 1.75H7.25C6.284 4 5.5 3.216 5.5 2.25z")] , children : & [] , } , crate :: SvgNode :: Element { name : "path" , attributes : & [("fill-rule" , "evenodd") , ("clip-rule" , "evenodd") , ("d" , "M5.5 11.75c0-.414.336-.75.75-.75h2.5c.414 0 .75.336.75.75 0 .414-.336.75-.75.75H6.25c-.414 0-.75-.336-.75-.75z")] , children : & [] , } , crate :: SvgNode :: Element { name : "path" , attributes : & [("fill-rule" , "evenodd") , ("clip-rule" , "evenodd") , ("d" , "M5.5 8.75c0-.414.336-.75.75-.75h2.5c.414 0 .75.336.75.75 0 .414-.336.75-.75.75H6.25c-.414 0-.75-.336-.75-.75z")] , children : & [] , } , crate :: SvgNode :: Element { name : "path" , attributes : & [("fill-rule" , "evenodd") , ("clip-rule" , "evenodd") , ("d" , "M10.5 11.75c0-.414.336-.75.75-.75h.5c.414 0 .75.336.75.75 0 .414-.336.75-.75.75h-.5c-.414 0-.75-.336-.75-.75z")] , children : & [] , } , crate :: SvgNode :: Element { name : "path" , attributes : & [("fill-rule" , "evenodd") , ("clip-rule" , "evenodd") , ("d" , "M10.5 8.75c0-.414.336-.75.75-.75h.5c.414 0 .75.336.75.75 0 .414-.336.75-.75.75h-.5c-.414 0-.75-.336-.75-.75z")] , children : & [] , }]] , } ;
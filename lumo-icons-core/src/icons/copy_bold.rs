@@ -0,0 +1,14 @@
+#![doc = r" GENERATED FILE"]
+pub const COPY_BOLD_META: &crate::IconMeta = &crate::IconMeta {
+    name: "copy-bold",
+    pascal_name: "CopyBold",
+    sets: &["ui", "micro-bold"],
+    styles: &["micro-bold"],
+    weight: Some("bold"),
+    categories: &["ui", "editing", "files"],
+    tags: &["copy", "duplicate", "clone"],
+    codepoint: Some(71001u32),
+    published_in: Some("1.0"),
+    updated_in: Some("1.0"),
+};
+pub const COPY_BOLD : & crate :: IconStyleData = & crate :: IconStyleData { markup : ["" , "" , "" , "<path d=\"M7 13H5c-1.105 0-2-.895-2-2V5c0-1.105.895-2 2-2h6c1.105 0 2 .895 2 2V7\" fill=\"none\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\" style=\"stroke: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)\"></path><rect x=\"7\" y=\"7\" width=\"10\" height=\"10\" rx=\"2\" ry=\"2\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></rect>" , "" , ""] , view_boxes : [crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32)] , meta : COPY_BOLD_META , # [cfg (feature = "nodes")] nodes : [& [] , & [] , & [] , & [crate :: SvgNode :: Element { name : "path" , attributes : & [("d" , "M7 13H5c-1.105 0-2-.895-2-2V5c0-1.105.895-2 2-2h6c1.105 0 2 .895 2 2V7") , ("fill" , "none") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2") , ("style" , "stroke: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)")] , children : & [] , } , crate :: SvgNode :: Element { name : "rect" , attributes : & [("x" , "7") , ("y" , "7") , ("width" , "10") , ("height" , "10") , ("rx" , "2") , ("ry" , "2") , ("fill" , "none") , ("stroke" , "currentColor") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2")] , children : & [] , }] , & [] , & []] , } ;
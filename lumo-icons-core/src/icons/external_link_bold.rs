@@ -0,0 +1,14 @@
+#![doc = r" GENERATED FILE"]
+pub const EXTERNAL_LINK_BOLD_META: &crate::IconMeta = &crate::IconMeta {
+    name: "external-link-bold",
+    pascal_name: "ExternalLinkBold",
+    sets: &["core", "flags", "micro-bold"],
+    styles: &["micro-bold"],
+    weight: Some("bold"),
+    categories: &["navigation", "ui"],
+    tags: &["open", "external", "outbound", "launch", "new-window"],
+    codepoint: Some(71002u32),
+    published_in: Some("1.0"),
+    updated_in: Some("1.0"),
+};
+pub const EXTERNAL_LINK_BOLD : & crate :: IconStyleData = & crate :: IconStyleData { markup : ["" , "" , "" , "<polyline points=\"12 12 12 8 8 8\" fill=\"none\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\" style=\"stroke: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)\"></polyline><line x1=\"3\" y1=\"17\" x2=\"12\" y2=\"8\" fill=\"none\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\" style=\"stroke: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)\"></line><path d=\"M7.95 17H13c1.657 0 3-1.343 3-3V6c0-1.657-1.343-3-3-3H7C5.343 3 4 4.343 4 6v5.05\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></path>" , "" , ""] , view_boxes : [crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32)] , meta : EXTERNAL_LINK_BOLD_META , # [cfg (feature = "nodes")] nodes : [& [] , & [] , & [] , & [crate :: SvgNode :: Element { name : "polyline" , attributes : & [("points" , "12 12 12 8 8 8") , ("fill" , "none") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2") , ("style" , "stroke: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)")] , children : & [] , } , crate :: SvgNode :: Element { name : "line" , attributes : & [("x1" , "3") , ("y1" , "17") , ("x2" , "12") , ("y2" , "8") , ("fill" , "none") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2") , ("style" , "stroke: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)")] , children : & [] , } , crate :: SvgNode :: Element { name : "path" , attributes : & [("d" , "M7.95 17H13c1.657 0 3-1.343 3-3V6c0-1.657-1.343-3-3-3H7C5.343 3 4 4.343 4 6v5.05") , ("fill" , "none") , ("stroke" , "currentColor") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2")] , children : & [] , }] , & [] , & []] , } ;
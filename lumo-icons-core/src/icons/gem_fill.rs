@@ -0,0 +1,14 @@
+#![doc = r" GENERATED FILE"]
+pub const GEM_FILL_META: &crate::IconMeta = &crate::IconMeta {
+    name: "gem-fill",
+    pascal_name: "GemFill",
+    sets: &["ui", "micro-bold"],
+    styles: &["micro-bold"],
+    weight: Some("bold"),
+    categories: &["fashion", "beauty", "ui"],
+    tags: &["diamond", "gem", "stone", "jewelry", "premium"],
+    codepoint: Some(71004u32),
+    published_in: Some("1.0"),
+    updated_in: Some("1.0"),
+};
+pub const GEM_FILL : & crate :: IconStyleData = & crate :: IconStyleData { markup : ["" , "" , "" , "<path d=\"M16.5 7.5H3.5c-.414 0-.75-.336-.75-.75S3.086 6 3.5 6h13c.414 0 .75.336.75.75s-.336.75-.75.75z\" style=\"fill: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)\"></path><path d=\"M10 17.75c-.323 0-.621-.209-.718-.535l-3-10c-.056-.182-.038-.38.047-.551l2-4c.186-.37.636-.52 1.006-.335.371.186.521.636.336 1.006L7.804 7.069l2.914 9.716c.119.396-.106.814-.503.933-.072.022-.144.032-.216.032z\" style=\"fill: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)\"></path><path d=\"M10.001 17.75c-.072 0-.144-.01-.216-.032-.397-.119-.622-.537-.503-.933l2.914-9.716-1.867-3.733c-.185-.371-.035-.821.336-1.007.371-.184.821-.034 1.006.336l2 4c.085.171.102.368.047.55l-3 10c-.097.326-.395.535-.718.535z\" style=\"fill: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)\"></path><path d=\"M16.547 5.911c.561.64.615 1.58.132 2.281l-5.712 8.285c-.45.709-1.484.709-1.933 0L3.322 8.192c-.484-.701-.429-1.641.131-2.281L5.402 3.683C5.782 3.249 6.331 3 6.908 3h6.184c.577 0 1.126.249 1.506.683l1.949 2.228z\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></path>" , "" , ""] , view_boxes : [crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32)] , meta : GEM_FILL_META , # [cfg (feature = "nodes")] nodes : [& [] , & [] , & [] , & [crate :: SvgNode :: Element { name : "path" , attributes : & [("d" , "M16.5 7.5H3.5c-.414 0-.75-.336-.75-.75S3.086 6 3.5 6h13c.414 0 .75.336.75.75s-.336.75-.75.75z") , ("style" , "fill: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)")] , children : & [] , } , crate :: SvgNode :: Element { name : "path" , attributes : & [("d" , "M10 17.75c-.323 0-.621-.209-.718-.535l-3-10c-.056-.182-.038-.38.047-.551l2-4c.186-.37.636-.52 1.006-.335.371.186.521.636.336 1.006L7.804 7.069l2.914 9.716c.119.396-.106.814-.503.933-.072.022-.144.032-.216.032z") , ("style" , "fill: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)")] , children : & [] , } , crate :: SvgNode :: Element { name : "path" , attributes : & [("d" , "M10.001 17.75c-.072 0-.144-.01-.216-.032-.397-.119-.622-.537-.503-.933l2.914-9.716-1.867-3.733c-.185-.371-.035-.821.336-1.007.371-.184.821-.034 1.006.336l2 4c.085.171.102.368.047.55l-3 10c-.097.326-.395.535-.718.535z") , ("style" , "fill: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)")] , children : & [] , } , crate :: SvgNode :: Element { name : "path" , attributes : & [("d" , "M16.547 5.911c.561.64.615 1.58.132 2.281l-5.712 8.285c-.45.709-1.484.709-1.933 0L3.322 8.192c-.484-.701-.429-1.641.131-2.281L5.402 3.683C5.782 3.249 6.331 3 6.908 3h6.184c.577 0 1.126.249 1.506.683l1.949 2.228z") , ("fill" , "none") , ("stroke" , "currentColor") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2")] , children : & [] , }] , & [] , & []] , } ;
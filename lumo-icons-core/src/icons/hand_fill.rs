@@ -0,0 +1,14 @@
+#![doc = r" GENERATED FILE"]
+pub const HAND_FILL_META: &crate::IconMeta = &crate::IconMeta {
+    name: "hand-fill",
+    pascal_name: "HandFill",
+    sets: &["ui", "micro-bold"],
+    styles: &["micro-bold"],
+    weight: Some("bold"),
+    categories: &["ui", "gestures"],
+    tags: &["hand", "gesture", "stop", "open", "sign"],
+    codepoint: Some(71006u32),
+    published_in: Some("1.0"),
+    updated_in: Some("1.0"),
+};
+pub const HAND_FILL : & crate :: IconStyleData = & crate :: IconStyleData { markup : ["" , "" , "" , "<line x1=\"16\" y1=\"5\" x2=\"16\" y2=\"11\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></line><line x1=\"13\" y1=\"4\" x2=\"13\" y2=\"12\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></line><line x1=\"10\" y1=\"3\" x2=\"10\" y2=\"13\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></line><line x1=\"7\" y1=\"4\" x2=\"7\" y2=\"12\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></line><line x1=\"7.384\" y1=\"15.082\" x2=\"3.5\" y2=\"10\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2.5\"></line><path d=\"M7 10.5V12l-.793 1.43C6.822 15.495 8.735 17 11 17c2.761 0 5-2.239 5-5V10.5H7z\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></path>" , "" , ""] , view_boxes : [crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32)] , meta : HAND_FILL_META , # [cfg (feature = "nodes")] nodes : [& [] , & [] , & [] , & [crate :: SvgNode :: Element { name : "line" , attributes : & [("x1" , "16") , ("y1" , "5") , ("x2" , "16") , ("y2" , "11") , ("fill" , "none") , ("stroke" , "currentColor") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2")] , children : & [] , } , crate :: SvgNode :: Element { name : "line" , attributes : & [("x1" , "13") , ("y1" , "4") , ("x2" , "13") , ("y2" , "12") , ("fill" , "none") , ("stroke" , "currentColor") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2")] , children : & [] , } , crate :: SvgNode :: Element { name : "line" , attributes : & [("x1" , "10") , ("y1" , "3") , ("x2" , "10") , ("y2" , "13") , ("fill" , "none") , ("stroke" , "currentColor") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2")] , children : & [] , } , crate :: SvgNode :: Element { name : "line" , attributes : & [("x1" , "7") , ("y1" , "4") , ("x2" , "7") , ("y2" , "12") , ("fill" , "none") , ("stroke" , "currentColor") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2")] , children : & [] , } , crate :: SvgNode :: Element { name : "line" , attributes : & [("x1" , "7.384") , ("y1" , "15.082") , ("x2" , "3.5") , ("y2" , "10") , ("fill" , "none") , ("stroke" , "currentColor") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2.5")] , children : & [] , } , crate :: SvgNode :: Element { name : "path" , attributes : & [("d" , "M7 10.5V12l-.793 1.43C6.822 15.495 8.735 17 11 17c2.761 0 5-2.239 5-5V10.5H7z") , ("stroke" , "currentColor") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2")] , children : & [] , }] , & [] , & []] , } ;
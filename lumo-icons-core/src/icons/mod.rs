@@ -0,0 +1,70 @@
+#[cfg(any(feature = "core", feature = "ui", feature = "business-finance"))]
+#[doc(hidden)]
+mod clipboard_list;
+#[cfg(any(feature = "core", feature = "ui", feature = "business-finance"))]
+#[doc(hidden)]
+pub use clipboard_list::*;
+#[cfg(any(feature = "ui", feature = "micro-bold"))]
+#[doc(hidden)]
+mod copy_bold;
+#[cfg(any(feature = "ui", feature = "micro-bold"))]
+#[doc(hidden)]
+pub use copy_bold::*;
+#[cfg(any(feature = "core", feature = "flags", feature = "micro-bold"))]
+#[doc(hidden)]
+mod external_link_bold;
+#[cfg(any(feature = "core", feature = "flags", feature = "micro-bold"))]
+#[doc(hidden)]
+pub use external_link_bold::*;
+#[cfg(any(feature = "ui", feature = "micro-bold"))]
+#[doc(hidden)]
+mod gem_fill;
+#[cfg(any(feature = "ui", feature = "micro-bold"))]
+#[doc(hidden)]
+pub use gem_fill::*;
+#[cfg(any(feature = "ui", feature = "micro-bold"))]
+#[doc(hidden)]
+mod hand_fill;
+#[cfg(any(feature = "ui", feature = "micro-bold"))]
+#[doc(hidden)]
+pub use hand_fill::*;
+#[cfg(any(feature = "ui", feature = "micro-bold"))]
+#[doc(hidden)]
+mod square_bars_fill;
+#[cfg(any(feature = "ui", feature = "micro-bold"))]
+#[doc(hidden)]
+pub use square_bars_fill::*;
+#[doc = r" Every icon enabled through Cargo features, ordered by name."]
+pub const ICONS: &[crate::IconData] = &[
+    #[cfg(any(feature = "core", feature = "ui", feature = "business-finance"))]
+    CLIPBOARD_LIST,
+    #[cfg(any(feature = "ui", feature = "micro-bold"))]
+    COPY_BOLD,
+    #[cfg(any(feature = "core", feature = "flags", feature = "micro-bold"))]
+    EXTERNAL_LINK_BOLD,
+    #[cfg(any(feature = "ui", feature = "micro-bold"))]
+    GEM_FILL,
+    #[cfg(any(feature = "ui", feature = "micro-bold"))]
+    HAND_FILL,
+    #[cfg(any(feature = "ui", feature = "micro-bold"))]
+    SQUARE_BARS_FILL,
+];
+#[doc = r" Looks up an icon by its kebab-case name (`gem-fill`) or its PascalCase"]
+#[doc = r" name (`GemFill`). Only icons enabled through Cargo features are found."]
+pub fn icon_by_name(name: &str) -> Option<crate::IconData> {
+    match name {
+        #[cfg(any(feature = "core", feature = "ui", feature = "business-finance"))]
+        "clipboard-list" | "ClipboardList" => Some(CLIPBOARD_LIST),
+        #[cfg(any(feature = "ui", feature = "micro-bold"))]
+        "copy-bold" | "CopyBold" => Some(COPY_BOLD),
+        #[cfg(any(feature = "core", feature = "flags", feature = "micro-bold"))]
+        "external-link-bold" | "ExternalLinkBold" => Some(EXTERNAL_LINK_BOLD),
+        #[cfg(any(feature = "ui", feature = "micro-bold"))]
+        "gem-fill" | "GemFill" => Some(GEM_FILL),
+        #[cfg(any(feature = "ui", feature = "micro-bold"))]
+        "hand-fill" | "HandFill" => Some(HAND_FILL),
+        #[cfg(any(feature = "ui", feature = "micro-bold"))]
+        "square-bars-fill" | "SquareBarsFill" => Some(SQUARE_BARS_FILL),
+        _ => None,
+    }
+}
@@ -0,0 +1,22 @@
+#![doc = r" GENERATED FILE"]
+pub const SQUARE_BARS_FILL_META: &crate::IconMeta = &crate::IconMeta {
+    name: "square-bars-fill",
+    pascal_name: "SquareBarsFill",
+    sets: &["ui", "micro-bold"],
+    styles: &["micro-bold"],
+    weight: Some("bold"),
+    categories: &["ui", "layout"],
+    tags: &[
+        "lines",
+        "list",
+        "settings",
+        "menu",
+        "square",
+        "navigation",
+        "bars",
+    ],
+    codepoint: Some(71005u32),
+    published_in: Some("1.0"),
+    updated_in: Some("1.0"),
+};
+pub const SQUARE_BARS_FILL : & crate :: IconStyleData = & crate :: IconStyleData { markup : ["" , "" , "" , "<rect x=\"3\" y=\"3\" width=\"14\" height=\"14\" rx=\"3\" ry=\"3\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></rect><line x1=\"13\" y1=\"8\" x2=\"7\" y2=\"8\" fill=\"none\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\" style=\"stroke: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)\"></line><line x1=\"13\" y1=\"12\" x2=\"7\" y2=\"12\" fill=\"none\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\" style=\"stroke: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)\"></line>" , "" , ""] , view_boxes : [crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32) , crate :: ViewBox :: new (0f32 , 0f32 , 20f32 , 20f32)] , meta : SQUARE_BARS_FILL_META , # [cfg (feature = "nodes")] nodes : [& [] , & [] , & [] , & [crate :: SvgNode :: Element { name : "rect" , attributes : & [("x" , "3") , ("y" , "3") , ("width" , "14") , ("height" , "14") , ("rx" , "3") , ("ry" , "3") , ("fill" , "none") , ("stroke" , "currentColor") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2")] , children : & [] , } , crate :: SvgNode :: Element { name : "line" , attributes : & [("x1" , "13") , ("y1" , "8") , ("x2" , "7") , ("y2" , "8") , ("fill" , "none") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2") , ("style" , "stroke: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)")] , children : & [] , } , crate :: SvgNode :: Element { name : "line" , attributes : & [("x1" , "13") , ("y1" , "12") , ("x2" , "7") , ("y2" , "12") , ("fill" , "none") , ("stroke-linecap" , "round") , ("stroke-linejoin" , "round") , ("stroke-width" , "2") , ("style" , "stroke: var(--lumo-icon-secondary-color, currentColor); opacity: var(--lumo-icon-secondary-opacity, 1)")] , children : & [] , }] , & [] , & []] , } ;
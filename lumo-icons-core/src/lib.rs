@@ -0,0 +1,169 @@
+#![doc = r" The framework-agnostic core of lumo-icons: the icon data, its metadata,"]
+#![doc = r" search and standalone SVG rendering."]
+#![doc = r""]
+#![doc = r" The crate is `no_std` (it only needs `alloc`), so the icons can be used"]
+#![doc = r" on servers, in other UI frameworks or on embedded targets without"]
+#![doc = r" pulling in Leptos. The `lumo-icons` crate builds its components on top."]
+#![doc = r""]
+#![doc = r" ```"]
+#![doc = r" use lumo_icons_core::{render_svg, IconStyle, RenderOptions, GEM_FILL};"]
+#![doc = r""]
+#![doc = r" let svg = render_svg(GEM_FILL, IconStyle::MicroBold, &RenderOptions::default());"]
+#![doc = r" ```"]
+#![no_std]
+extern crate alloc;
+use alloc::string::{String, ToString};
+mod animation;
+pub use animation::*;
+mod fallback;
+pub use fallback::*;
+mod icons;
+pub use icons::*;
+#[cfg(feature = "nodes")]
+mod nodes;
+#[cfg(feature = "nodes")]
+pub use nodes::*;
+mod render;
+pub use render::*;
+mod search;
+pub use search::*;
+mod sprite;
+pub use sprite::*;
+mod view_box;
+pub use view_box::*;
+#[doc = r" An icon's style."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IconStyle {
+    #[default]
+    Core,
+    Flags,
+    Glass,
+    MicroBold,
+    SocialMedia,
+    Ui,
+}
+impl IconStyle {
+    #[doc = r" Every style, in declaration order."]
+    pub const ALL: [IconStyle; 6usize] = [
+        IconStyle::Core,
+        IconStyle::Flags,
+        IconStyle::Glass,
+        IconStyle::MicroBold,
+        IconStyle::SocialMedia,
+        IconStyle::Ui,
+    ];
+    #[doc = r" The kebab-case name of the style, matching its `assets` folder."]
+    pub const fn name(self) -> &'static str {
+        match self {
+            IconStyle::Core => "core",
+            IconStyle::Flags => "flags",
+            IconStyle::Glass => "glass",
+            IconStyle::MicroBold => "micro-bold",
+            IconStyle::SocialMedia => "social-media",
+            IconStyle::Ui => "ui",
+        }
+    }
+}
+#[doc = r" Descriptive metadata for an icon, as recorded in `metadata/icons.json`."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IconMeta {
+    #[doc = r" The kebab-case name of the icon, e.g. `gem-fill`."]
+    pub name: &'static str,
+    #[doc = r" The PascalCase name of the icon, e.g. `GemFill`."]
+    pub pascal_name: &'static str,
+    #[doc = r" The sets (and therefore Cargo features) the icon belongs to."]
+    pub sets: &'static [&'static str],
+    #[doc = r" The styles the icon is declared to be drawn in."]
+    pub styles: &'static [&'static str],
+    #[doc = r" The stroke weight the icon was drawn with."]
+    pub weight: Option<&'static str>,
+    #[doc = r" Broad categories the icon can be grouped under."]
+    pub categories: &'static [&'static str],
+    #[doc = r" Search keywords associated with the icon."]
+    pub tags: &'static [&'static str],
+    #[doc = r" The icon's codepoint in the icon font."]
+    pub codepoint: Option<u32>,
+    #[doc = r" The version the icon was first published in, as written in the"]
+    #[doc = r#" metadata, e.g. `"1.0"`."#]
+    pub published_in: Option<&'static str>,
+    #[doc = r#" The version the icon was last updated in, e.g. `"1.0"`."#]
+    pub updated_in: Option<&'static str>,
+}
+#[doc = r" The SVG path data for all styles of a particular icon, with the"]
+#[doc = r" `viewBox` each style is drawn in."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IconStyleData {
+    markup: [&'static str; 6usize],
+    view_boxes: [ViewBox; 6usize],
+    meta: &'static IconMeta,
+    #[cfg(feature = "nodes")]
+    nodes: [&'static [SvgNode]; 6usize],
+}
+impl IconStyleData {
+    pub fn get(&self, style: IconStyle) -> &'static str {
+        match style {
+            IconStyle::Core => self.markup[0usize],
+            IconStyle::Flags => self.markup[1usize],
+            IconStyle::Glass => self.markup[2usize],
+            IconStyle::MicroBold => self.markup[3usize],
+            IconStyle::SocialMedia => self.markup[4usize],
+            IconStyle::Ui => self.markup[5usize],
+        }
+    }
+    #[doc = r" The `viewBox` the given style is drawn in. Styles the icon isn't"]
+    #[doc = r" drawn in have the `viewBox` of its first style."]
+    pub fn view_box(&self, style: IconStyle) -> ViewBox {
+        match style {
+            IconStyle::Core => self.view_boxes[0usize],
+            IconStyle::Flags => self.view_boxes[1usize],
+            IconStyle::Glass => self.view_boxes[2usize],
+            IconStyle::MicroBold => self.view_boxes[3usize],
+            IconStyle::SocialMedia => self.view_boxes[4usize],
+            IconStyle::Ui => self.view_boxes[5usize],
+        }
+    }
+    #[doc = r" The markup of the given style as a tree of elements, which is"]
+    #[doc = r" empty for styles the icon isn't drawn in."]
+    #[cfg(feature = "nodes")]
+    pub fn nodes(&self, style: IconStyle) -> &'static [SvgNode] {
+        match style {
+            IconStyle::Core => self.nodes[0usize],
+            IconStyle::Flags => self.nodes[1usize],
+            IconStyle::Glass => self.nodes[2usize],
+            IconStyle::MicroBold => self.nodes[3usize],
+            IconStyle::SocialMedia => self.nodes[4usize],
+            IconStyle::Ui => self.nodes[5usize],
+        }
+    }
+    #[doc = r" Whether the icon is drawn in the given style."]
+    pub fn has_style(&self, style: IconStyle) -> bool {
+        !self.get(style).is_empty()
+    }
+    #[doc = r" The styles the icon is drawn in, in declaration order."]
+    pub fn available_styles(&self) -> impl Iterator<Item = IconStyle> + '_ {
+        IconStyle::ALL
+            .into_iter()
+            .filter(|&style| self.has_style(style))
+    }
+    #[doc = r" The icon's metadata, such as its name, tags and categories."]
+    pub const fn meta(&self) -> &'static IconMeta {
+        self.meta
+    }
+}
+pub type IconData = &'static IconStyleData;
+#[doc = r" The error returned when parsing an unknown icon name."]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIconError(String);
+impl core::fmt::Display for ParseIconError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown icon `{}`", self.0)
+    }
+}
+impl core::error::Error for ParseIconError {}
+impl core::str::FromStr for IconData {
+    type Err = ParseIconError;
+    #[doc = r" Parses an icon from its kebab-case or PascalCase name, see [`icon_by_name`]."]
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        icon_by_name(name).ok_or_else(|| ParseIconError(name.to_string()))
+    }
+}
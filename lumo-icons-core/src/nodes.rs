@@ -0,0 +1,37 @@
+//! Icons as trees of elements, for building them node by node instead of
+//! parsing their markup, e.g. on pages that enforce Trusted Types.
+
+use crate::{IconStyle, IconStyleData, StyleFallback};
+
+/// A node of an icon's markup: an SVG element or the text inside one.
+///
+/// The tree holds the same elements and attributes as the markup returned by
+/// [`IconStyleData::get`], with the attribute values unescaped.
+///
+/// ```
+/// use lumo_icons_core::{IconStyle, SvgNode, GEM_FILL};
+///
+/// let nodes = GEM_FILL.nodes(IconStyle::MicroBold);
+/// assert!(matches!(nodes[0], SvgNode::Element { name: "path", .. }));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SvgNode {
+    Element {
+        name: &'static str,
+        attributes: &'static [(&'static str, &'static str)],
+        children: &'static [SvgNode],
+    },
+    Text(&'static str),
+}
+
+impl IconStyleData {
+    /// The nodes for `style`, or for its fallback if the icon isn't drawn in it.
+    pub fn nodes_or_fallback(
+        &self,
+        style: IconStyle,
+        fallback: StyleFallback,
+    ) -> &'static [SvgNode] {
+        self.resolve_style(style, fallback)
+            .map_or(&[], |style| self.nodes(style))
+    }
+}
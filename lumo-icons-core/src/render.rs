@@ -0,0 +1,324 @@
+//! Rendering icons to standalone SVG documents, without a UI framework.
+
+use crate::{render_sprite_use, IconAnimation, IconData, IconStyle, StyleFallback, ViewBox};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Options for [`render_svg`], matching the props of the `Icon` components.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderOptions {
+    /// The icon's height, as a CSS length. Defaults to `1em`. Its width
+    /// follows from the icon's aspect ratio, so square icons are `size` wide.
+    pub size: String,
+    /// The icon's width, as a CSS length, overriding `size`. Without a
+    /// `height`, the height follows from the icon's aspect ratio.
+    pub width: Option<String>,
+    /// The icon's height, as a CSS length, overriding `size`. Without a
+    /// `width`, the width follows from the icon's aspect ratio.
+    pub height: Option<String>,
+    /// How the icon is fitted into a box of another aspect ratio, as the value
+    /// of the `preserveAspectRatio` attribute, such as `xMinYMid meet` or
+    /// `none`. Defaults to centering it without distortion.
+    pub preserve_aspect_ratio: Option<String>,
+    /// The icon's color, as a CSS color. Defaults to `currentColor`.
+    pub color: String,
+    /// The color of the icon's secondary (duotone) layers, as a CSS color.
+    /// Defaults to `color`.
+    pub secondary_color: Option<String>,
+    /// The opacity of the icon's secondary layers, from `0.0` to `1.0`.
+    /// Defaults to the opacity they were drawn with.
+    pub secondary_opacity: Option<f32>,
+    /// Whether to flip the icon horizontally.
+    pub mirrored: bool,
+    /// Whether to flip the icon vertically.
+    pub flip_vertical: bool,
+    /// How far to rotate the icon clockwise, in degrees.
+    pub rotate: f32,
+    /// Any other SVG transform, such as `skewX(10)`. It is applied before the
+    /// rotation and flips, and like them centered on the icon.
+    pub transform: Option<String>,
+    /// A looping animation for the icon. The page needs to include
+    /// [`ANIMATION_STYLESHEET`](crate::ANIMATION_STYLESHEET) for it to play.
+    pub animation: IconAnimation,
+    /// How fast the animation plays, relative to its normal speed.
+    pub animation_speed: Option<f32>,
+    /// How many times the animation plays. Defaults to forever.
+    pub animation_iterations: Option<u32>,
+    /// An accessible name for the icon. Icons without one are decorative and
+    /// hidden from assistive technology.
+    pub title: Option<String>,
+    /// What to render if the icon isn't drawn in the requested style.
+    pub fallback: StyleFallback,
+    /// Whether to point to the icon's `<symbol>` in a sprite sheet (see
+    /// [`render_sprite_sheet`](crate::render_sprite_sheet)) instead of
+    /// inlining its markup.
+    pub sprite: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            size: "1em".to_string(),
+            width: None,
+            height: None,
+            preserve_aspect_ratio: None,
+            color: "currentColor".to_string(),
+            secondary_color: None,
+            secondary_opacity: None,
+            mirrored: false,
+            flip_vertical: false,
+            rotate: 0.0,
+            transform: None,
+            animation: IconAnimation::None,
+            animation_speed: None,
+            animation_iterations: None,
+            title: None,
+            fallback: StyleFallback::default(),
+            sprite: false,
+        }
+    }
+}
+
+/// The `width` and `height` of an icon drawn in `view_box`, shared by
+/// [`render_svg`] and the framework components.
+///
+/// `width` and `height` override `size`, which is the icon's height. When only
+/// one of them is given, the other follows from the aspect ratio of the view
+/// box, so that icons that aren't square keep their proportions.
+///
+/// ```
+/// use lumo_icons_core::{icon_size, ViewBox};
+///
+/// let flag = ViewBox::new(0.0, 0.0, 30.0, 20.0);
+/// assert_eq!(icon_size(flag, "1em", None, None), ("1.5em".into(), "1em".into()));
+/// assert_eq!(icon_size(flag, "1em", Some("60px"), None), ("60px".into(), "40px".into()));
+/// ```
+pub fn icon_size(
+    view_box: ViewBox,
+    size: &str,
+    width: Option<&str>,
+    height: Option<&str>,
+) -> (String, String) {
+    let ratio = view_box.aspect_ratio();
+    match (width, height) {
+        (Some(width), Some(height)) => (width.to_string(), height.to_string()),
+        (Some(width), None) => (width.to_string(), scale_length(width, 1.0 / ratio)),
+        (None, Some(height)) => (scale_length(height, ratio), height.to_string()),
+        (None, None) => (scale_length(size, ratio), size.to_string()),
+    }
+}
+
+/// Multiplies a CSS length by `factor`, keeping its unit. Lengths that aren't a
+/// plain number and unit, such as `var(--icon-size)`, are scaled with `calc()`.
+fn scale_length(length: &str, factor: f32) -> String {
+    if factor == 1.0 || !factor.is_finite() {
+        return length.to_string();
+    }
+
+    let length = length.trim();
+    let unit_start = length
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')))
+        .unwrap_or(length.len());
+    let (number, unit) = length.split_at(unit_start);
+    let is_unit = unit == "%" || unit.chars().all(|c| c.is_ascii_alphabetic());
+    match number.parse::<f32>() {
+        Ok(number) if is_unit => format!("{}{unit}", format_number(number * factor)),
+        _ => format!("calc({length} * {})", format_number(factor)),
+    }
+}
+
+/// Formats a number with at most three decimals and no trailing zeros.
+fn format_number(number: f32) -> String {
+    let formatted = format!("{number:.3}");
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+    match formatted {
+        "-0" => "0".to_string(),
+        formatted => formatted.to_string(),
+    }
+}
+
+/// The transform of a rotated, flipped or otherwise transformed icon, shared by
+/// [`render_svg`] and the framework components.
+///
+/// The parts are composed around the center of the icon's `view_box`, so the
+/// icon stays in place: `transform` applies first, then the flips and then the
+/// rotation.
+pub fn icon_transform(
+    view_box: ViewBox,
+    rotate: f32,
+    mirrored: bool,
+    flip_vertical: bool,
+    transform: Option<&str>,
+) -> Option<String> {
+    let mut parts = Vec::new();
+    if rotate % 360.0 != 0.0 {
+        parts.push(format!("rotate({rotate})"));
+    }
+    match (mirrored, flip_vertical) {
+        (false, false) => {}
+        (true, false) => parts.push("scale(-1 1)".to_string()),
+        (false, true) => parts.push("scale(1 -1)".to_string()),
+        (true, true) => parts.push("scale(-1 -1)".to_string()),
+    }
+    if let Some(transform) = transform.map(str::trim).filter(|t| !t.is_empty()) {
+        parts.push(transform.to_string());
+    }
+    if parts.is_empty() {
+        return None;
+    }
+
+    let (x, y) = view_box.center();
+    Some(format!(
+        "translate({x} {y}) {} translate({} {})",
+        parts.join(" "),
+        0.0 - x,
+        0.0 - y
+    ))
+}
+
+/// Wraps an icon's markup in a `<g>` with the given transform, if there is one.
+///
+/// Transforming the content rather than the `<svg>` itself keeps the origin
+/// of the transform consistent across browsers.
+pub fn transform_group(markup: &str, transform: Option<&str>) -> String {
+    match transform {
+        Some(transform) => {
+            let mut group = String::from("<g");
+            push_attribute(&mut group, "transform", transform);
+            group.push('>');
+            group.push_str(markup);
+            group.push_str("</g>");
+            group
+        }
+        None => markup.to_string(),
+    }
+}
+
+/// The `style` of an icon, which sets the custom properties its secondary
+/// (duotone) layers and its animation are drawn with.
+pub fn icon_style(
+    secondary_color: Option<&str>,
+    secondary_opacity: Option<f32>,
+    animation_speed: Option<f32>,
+    animation_iterations: Option<u32>,
+) -> Option<String> {
+    let mut declarations = Vec::new();
+    if let Some(color) = secondary_color {
+        declarations.push(format!("--lumo-icon-secondary-color: {color}"));
+    }
+    if let Some(opacity) = secondary_opacity {
+        declarations.push(format!("--lumo-icon-secondary-opacity: {opacity}"));
+    }
+    if let Some(speed) = animation_speed {
+        declarations.push(format!("--lumo-icon-animation-speed: {speed}"));
+    }
+    if let Some(iterations) = animation_iterations {
+        declarations.push(format!("--lumo-icon-animation-iterations: {iterations}"));
+    }
+    (!declarations.is_empty()).then(|| declarations.join("; "))
+}
+
+/// The `role` of an icon: labelled icons are images in their own right.
+pub fn icon_role(labelled: bool) -> Option<&'static str> {
+    labelled.then_some("img")
+}
+
+/// The `aria-hidden` attribute of an icon: unlabelled icons are decorative.
+pub fn icon_aria_hidden(labelled: bool) -> Option<&'static str> {
+    (!labelled).then_some("true")
+}
+
+/// Renders an icon to a complete SVG document, with the same markup the `Icon`
+/// components produce.
+///
+/// ```
+/// use lumo_icons_core::{render_svg, IconStyle, RenderOptions, GEM_FILL};
+///
+/// let svg = render_svg(
+///     GEM_FILL,
+///     IconStyle::MicroBold,
+///     &RenderOptions {
+///         size: "32px".to_string(),
+///         color: "#AE2983".to_string(),
+///         ..Default::default()
+///     },
+/// );
+/// assert!(svg.starts_with("<svg "));
+/// ```
+pub fn render_svg(icon: IconData, style: IconStyle, options: &RenderOptions) -> String {
+    let labelled = options.title.is_some();
+
+    let mut svg = String::from(r#"<svg xmlns="http://www.w3.org/2000/svg""#);
+    if let Some(role) = icon_role(labelled) {
+        push_attribute(&mut svg, "role", role);
+    }
+    if let Some(aria_hidden) = icon_aria_hidden(labelled) {
+        push_attribute(&mut svg, "aria-hidden", aria_hidden);
+    }
+    if let Some(title) = &options.title {
+        push_attribute(&mut svg, "aria-label", title);
+    }
+    let view_box = icon.view_box_or_fallback(style, options.fallback);
+    let (width, height) = icon_size(
+        view_box,
+        &options.size,
+        options.width.as_deref(),
+        options.height.as_deref(),
+    );
+    push_attribute(&mut svg, "width", &width);
+    push_attribute(&mut svg, "height", &height);
+    push_attribute(&mut svg, "fill", &options.color);
+    push_attribute(&mut svg, "color", &options.color);
+    push_attribute(&mut svg, "viewBox", &view_box.to_string());
+    if let Some(preserve_aspect_ratio) = &options.preserve_aspect_ratio {
+        push_attribute(&mut svg, "preserveAspectRatio", preserve_aspect_ratio);
+    }
+    if let Some(class) = options.animation.class() {
+        push_attribute(&mut svg, "class", class);
+    }
+    let css = icon_style(
+        options.secondary_color.as_deref(),
+        options.secondary_opacity,
+        options.animation_speed,
+        options.animation_iterations,
+    );
+    if let Some(css) = css {
+        // Leptos always ends the `style` with a `;`; matching it keeps the markup
+        // of all the components the same.
+        push_attribute(&mut svg, "style", &format!("{css};"));
+    }
+    svg.push('>');
+    let markup = if options.sprite {
+        icon.resolve_style(style, options.fallback)
+            .map(|style| render_sprite_use(icon, style))
+            .unwrap_or_default()
+    } else {
+        icon.get_or_fallback(style, options.fallback).to_string()
+    };
+    let transform = icon_transform(
+        view_box,
+        options.rotate,
+        options.mirrored,
+        options.flip_vertical,
+        options.transform.as_deref(),
+    );
+    svg.push_str(&transform_group(&markup, transform.as_deref()));
+    svg.push_str("</svg>");
+    svg
+}
+
+pub(crate) fn push_attribute(svg: &mut String, name: &str, value: &str) {
+    svg.push(' ');
+    svg.push_str(name);
+    svg.push_str("=\"");
+    for c in value.chars() {
+        match c {
+            '&' => svg.push_str("&amp;"),
+            '"' => svg.push_str("&quot;"),
+            '<' => svg.push_str("&lt;"),
+            c => svg.push(c),
+        }
+    }
+    svg.push('"');
+}
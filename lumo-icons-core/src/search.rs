@@ -0,0 +1,131 @@
+//! Ranked search over the names, tags and categories of the icon catalog.
+
+use crate::{IconData, ICONS};
+use alloc::vec::Vec;
+
+// How much each kind of match contributes to an icon's score.
+const NAME_EXACT: u32 = 100;
+const NAME_PREFIX: u32 = 80;
+const NAME_WORD: u32 = 60;
+const TAG_EXACT: u32 = 50;
+const TAG_PREFIX: u32 = 35;
+const CATEGORY_EXACT: u32 = 30;
+const CATEGORY_PREFIX: u32 = 20;
+const FUZZY: u32 = 10;
+
+/// An icon returned by [`search_icons`], along with how well it matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// The matching icon.
+    pub icon: IconData,
+    /// The relevance of the match; higher is better.
+    pub score: u32,
+}
+
+/// Searches the enabled icons by name, tags and categories.
+///
+/// The query is split on whitespace and every term has to match the icon
+/// somewhere, either exactly, as a prefix, or fuzzily (a small typo or an
+/// abbreviation of the name). Results are ordered by descending score, then
+/// by name. An empty query returns every enabled icon with a score of zero.
+///
+/// ```
+/// use lumo_icons_core::{search_icons, GEM_FILL};
+///
+/// let results = search_icons("diamond");
+/// assert_eq!(results[0].icon, GEM_FILL);
+/// ```
+pub fn search_icons(query: &str) -> Vec<SearchMatch> {
+    let query = query.to_lowercase();
+    let terms: Vec<&str> = query.split_whitespace().collect();
+
+    let mut matches: Vec<SearchMatch> = ICONS
+        .iter()
+        .filter_map(|&icon| {
+            terms
+                .iter()
+                .map(|term| score_term(icon, term))
+                .try_fold(0, |total, score| score.map(|score| total + score))
+                .map(|score| SearchMatch { icon, score })
+        })
+        .collect();
+
+    matches.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.icon.meta().name.cmp(b.icon.meta().name))
+    });
+    matches
+}
+
+/// The best score a single query term achieves against an icon, if any.
+fn score_term(icon: IconData, term: &str) -> Option<u32> {
+    let meta = icon.meta();
+    let name = meta.name;
+    let words = || name.split('-');
+
+    let score = if name == term {
+        NAME_EXACT
+    } else if name.starts_with(term) {
+        NAME_PREFIX
+    } else if words().any(|word| word.starts_with(term)) {
+        NAME_WORD
+    } else if meta.tags.contains(&term) {
+        TAG_EXACT
+    } else if meta.tags.iter().any(|tag| tag.starts_with(term)) {
+        TAG_PREFIX
+    } else if meta.categories.contains(&term) {
+        CATEGORY_EXACT
+    } else if meta.categories.iter().any(|c| c.starts_with(term)) {
+        CATEGORY_PREFIX
+    } else if (term.len() > 1 && is_subsequence(term, name))
+        || words()
+            .chain(meta.tags.iter().copied())
+            .any(|word| is_close(term, word))
+    {
+        FUZZY
+    } else {
+        return None;
+    };
+
+    Some(score)
+}
+
+/// Whether all characters of `needle` appear in `haystack`, in order, so that
+/// abbreviations like `sqbars` find `square-bars-fill`.
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut haystack = haystack.chars();
+    needle
+        .chars()
+        .filter(|&c| c != '-')
+        .all(|c| haystack.any(|h| h == c))
+}
+
+/// Whether `term` is within a typo of `word`: one edit for short terms, two
+/// for longer ones. Very short terms never match fuzzily.
+fn is_close(term: &str, word: &str) -> bool {
+    let max_distance = match term.chars().count() {
+        0..=3 => return false,
+        4..=6 => 1,
+        _ => 2,
+    };
+    edit_distance(term, word) <= max_distance
+}
+
+/// The Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[b.len()]
+}
@@ -0,0 +1,83 @@
+//! Sprite sheets, which hold the markup of each icon once as a `<symbol>` that
+//! any number of icons can then point to with `<use>`.
+
+use crate::{render::push_attribute, IconData, IconStyle, IconStyleData};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+impl IconStyleData {
+    /// The id of this icon's `<symbol>` in a sprite sheet: the kebab-case
+    /// constant name followed by the style, e.g. `lumo-gem-fill--micro-bold`.
+    pub fn symbol_id(&self, style: IconStyle) -> String {
+        format!("lumo-{}--{}", self.meta().name, style.name())
+    }
+}
+
+/// The attributes of the `<use>` element that renders an icon from a sprite
+/// sheet.
+pub fn sprite_use_attributes(icon: IconData, style: IconStyle) -> Vec<(&'static str, String)> {
+    let mut attributes = vec![("href", format!("#{}", icon.symbol_id(style)))];
+    // The symbol fills the `<use>`, which starts at the origin unless it is
+    // moved to where the icon's view box does.
+    let view_box = icon.view_box(style);
+    if view_box.min_x() != 0.0 {
+        attributes.push(("x", view_box.min_x().to_string()));
+    }
+    if view_box.min_y() != 0.0 {
+        attributes.push(("y", view_box.min_y().to_string()));
+    }
+    attributes
+}
+
+/// The `<use>` element that renders an icon from a sprite sheet.
+pub fn render_sprite_use(icon: IconData, style: IconStyle) -> String {
+    let mut markup = String::from("<use");
+    for (name, value) in sprite_use_attributes(icon, style) {
+        push_attribute(&mut markup, name, &value);
+    }
+    markup.push_str("></use>");
+    markup
+}
+
+/// The `<symbol>` holding an icon's markup in a sprite sheet.
+pub fn render_symbol(icon: IconData, style: IconStyle) -> String {
+    let mut markup = String::from("<symbol");
+    push_attribute(&mut markup, "id", &icon.symbol_id(style));
+    push_attribute(&mut markup, "viewBox", &icon.view_box(style).to_string());
+    markup.push('>');
+    markup.push_str(icon.get(style));
+    markup.push_str("</symbol>");
+    markup
+}
+
+/// The `<symbol>`s of the given icons, rendering duplicate entries only once.
+pub fn render_symbols(icons: impl IntoIterator<Item = (IconData, IconStyle)>) -> String {
+    let mut markup = String::new();
+    let mut rendered = Vec::new();
+    for (icon, style) in icons {
+        if !rendered.contains(&(icon, style)) {
+            markup.push_str(&render_symbol(icon, style));
+            rendered.push((icon, style));
+        }
+    }
+    markup
+}
+
+/// Renders a hidden `<svg>` holding a `<symbol>` for each of the given icons,
+/// to be placed anywhere in the same document as the icons pointing to them.
+pub fn render_sprite_sheet(icons: impl IntoIterator<Item = (IconData, IconStyle)>) -> String {
+    let mut svg = String::from(r#"<svg xmlns="http://www.w3.org/2000/svg" aria-hidden="true""#);
+    push_attribute(&mut svg, "style", SPRITE_SHEET_STYLE);
+    svg.push('>');
+    svg.push_str(&render_symbols(icons));
+    svg.push_str("</svg>");
+    svg
+}
+
+/// The inline style of a sprite sheet. It is taken out of the layout rather than
+/// hidden with `display: none`, which would break some paint servers.
+pub const SPRITE_SHEET_STYLE: &str = "position: absolute; width: 0; height: 0; overflow: hidden";
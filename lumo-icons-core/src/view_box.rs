@@ -0,0 +1,88 @@
+//! The coordinate systems icons are drawn in.
+
+use crate::{IconStyle, IconStyleData};
+use core::fmt;
+
+/// The `viewBox` an icon is drawn in, in the units of its source file.
+///
+/// Its [`Display`](fmt::Display) output is the value of the `viewBox` attribute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewBox {
+    min_x: f32,
+    min_y: f32,
+    width: f32,
+    height: f32,
+}
+
+// `new` only accepts finite numbers, so every view box is equal to itself.
+impl Eq for ViewBox {}
+
+impl ViewBox {
+    /// # Panics
+    ///
+    /// If any of the numbers is infinite or NaN.
+    pub const fn new(min_x: f32, min_y: f32, width: f32, height: f32) -> Self {
+        assert!(
+            min_x.is_finite() && min_y.is_finite() && width.is_finite() && height.is_finite(),
+            "a view box is made of finite numbers"
+        );
+        Self {
+            min_x,
+            min_y,
+            width,
+            height,
+        }
+    }
+
+    /// The left edge.
+    pub fn min_x(self) -> f32 {
+        self.min_x
+    }
+
+    /// The top edge.
+    pub fn min_y(self) -> f32 {
+        self.min_y
+    }
+
+    /// The width, in the units of the source file.
+    pub fn width(self) -> f32 {
+        self.width
+    }
+
+    /// The height, in the units of the source file.
+    pub fn height(self) -> f32 {
+        self.height
+    }
+
+    /// The center of the view box, which rotations and flips are composed around.
+    pub fn center(self) -> (f32, f32) {
+        (
+            self.min_x + self.width / 2.0,
+            self.min_y + self.height / 2.0,
+        )
+    }
+
+    /// The width of the view box divided by its height: above `1.0` for wide
+    /// icons and below it for tall ones.
+    pub fn aspect_ratio(self) -> f32 {
+        self.width / self.height
+    }
+}
+
+impl IconStyleData {
+    /// The aspect ratio of the given style, which sets how wide the icon is
+    /// rendered for a given `size`.
+    pub fn aspect_ratio(&self, style: IconStyle) -> f32 {
+        self.view_box(style).aspect_ratio()
+    }
+}
+
+impl fmt::Display for ViewBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.min_x, self.min_y, self.width, self.height
+        )
+    }
+}
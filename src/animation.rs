@@ -0,0 +1,31 @@
+//! Adding the animation stylesheet to the page, shared by the framework components.
+
+/// Adds [`ANIMATION_STYLESHEET`](crate::ANIMATION_STYLESHEET) to the document's
+/// `<head>`, unless an earlier icon already did.
+///
+/// This only does something in the browser; server-rendered pages pick the
+/// stylesheet up once they hydrate.
+pub(crate) fn inject_stylesheet() {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use crate::{ANIMATION_STYLESHEET, ANIMATION_STYLESHEET_ID};
+        #[cfg(feature = "leptos")]
+        use leptos::web_sys;
+
+        let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+            return;
+        };
+        if document
+            .get_element_by_id(ANIMATION_STYLESHEET_ID)
+            .is_some()
+        {
+            return;
+        }
+        let (Some(head), Ok(style)) = (document.head(), document.create_element("style")) else {
+            return;
+        };
+        style.set_id(ANIMATION_STYLESHEET_ID);
+        style.set_text_content(Some(ANIMATION_STYLESHEET));
+        let _ = head.append_child(&style);
+    }
+}
@@ -0,0 +1,52 @@
+//! Development-time warnings shared by the framework components.
+
+use crate::{IconData, IconStyle, StyleFallback};
+
+/// Warns, in debug builds only, when an icon isn't drawn in the requested
+/// style, since it would otherwise just show up as a blank space or in an
+/// unexpected style.
+pub(crate) fn warn_missing_style(icon: IconData, style: IconStyle, fallback: StyleFallback) {
+    #[cfg(debug_assertions)]
+    if !icon.has_style(style) {
+        let name = icon.meta().name;
+        let message = match icon.resolve_style(style, fallback) {
+            Some(resolved) => format!(
+                "lumo-icons: `{name}` has no `{}` style, rendering `{}` instead",
+                style.name(),
+                resolved.name()
+            ),
+            None => format!(
+                "lumo-icons: `{name}` has no `{}` style and no fallback, rendering nothing",
+                style.name()
+            ),
+        };
+        warn(&message);
+    }
+
+    #[cfg(not(debug_assertions))]
+    let _ = (icon, style, fallback);
+}
+
+/// Warns, in debug builds only, when an icon is rendered in sprite mode without
+/// a sprite sheet to point to.
+#[cfg(feature = "leptos")]
+pub(crate) fn warn_missing_sprite_sheet(icon: IconData) {
+    #[cfg(debug_assertions)]
+    warn(&format!(
+        "lumo-icons: `{}` uses `sprite` outside of a `SpriteSheet`, inlining it instead",
+        icon.meta().name
+    ));
+
+    #[cfg(not(debug_assertions))]
+    let _ = icon;
+}
+
+#[cfg(debug_assertions)]
+fn warn(message: &str) {
+    #[cfg(feature = "leptos")]
+    leptos::logging::warn!("{message}");
+    #[cfg(all(not(feature = "leptos"), target_arch = "wasm32"))]
+    web_sys::console::warn_1(&message.into());
+    #[cfg(all(not(feature = "leptos"), not(target_arch = "wasm32")))]
+    eprintln!("{message}");
+}
@@ -0,0 +1,109 @@
+//! The `Icon` and `SpriteSheet` components for [Dioxus](https://dioxuslabs.com).
+
+use crate::{
+    animation, diagnostics, icon_aria_hidden, icon_role, icon_size, icon_style, icon_transform,
+    render_sprite_use, render_symbols, transform_group, IconAnimation, IconData, IconStyle,
+    StyleFallback, SPRITE_SHEET_STYLE,
+};
+use ::dioxus::prelude::*;
+
+/// Renders an icon, with the same props and markup as the Leptos `Icon`.
+///
+/// ```
+/// use dioxus::prelude::*;
+/// use lumo_icons::{dioxus::Icon, IconStyle, GEM_FILL};
+///
+/// fn App() -> Element {
+///     rsx! {
+///         Icon { icon: GEM_FILL, style: IconStyle::MicroBold, size: "32px" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Icon(
+    icon: IconData,
+    #[props(default)] style: IconStyle,
+    #[props(into, default = "1em".to_string())] size: String,
+    #[props(into)] width: Option<String>,
+    #[props(into)] height: Option<String>,
+    #[props(into)] preserve_aspect_ratio: Option<String>,
+    #[props(into, default = "currentColor".to_string())] color: String,
+    #[props(into)] secondary_color: Option<String>,
+    secondary_opacity: Option<f32>,
+    #[props(default)] mirrored: bool,
+    #[props(default)] flip_vertical: bool,
+    #[props(default)] rotate: f32,
+    #[props(into)] transform: Option<String>,
+    #[props(default)] animation: IconAnimation,
+    animation_speed: Option<f32>,
+    animation_iterations: Option<u32>,
+    #[props(into)] title: Option<String>,
+    #[props(default)] fallback: StyleFallback,
+    #[props(default)] sprite: bool,
+) -> Element {
+    use_effect(use_reactive!(|animation| {
+        if animation != IconAnimation::None {
+            animation::inject_stylesheet();
+        }
+    }));
+
+    let labelled = title.is_some();
+    // Leptos always ends the `style` with a `;`, which keeps the markup the same.
+    let css = icon_style(
+        secondary_color.as_deref(),
+        secondary_opacity,
+        animation_speed,
+        animation_iterations,
+    )
+    .map(|css| format!("{css};"));
+    diagnostics::warn_missing_style(icon, style, fallback);
+    let markup = if sprite {
+        icon.resolve_style(style, fallback)
+            .map(|style| render_sprite_use(icon, style))
+            .unwrap_or_default()
+    } else {
+        icon.get_or_fallback(style, fallback).to_string()
+    };
+    let view_box = icon.view_box_or_fallback(style, fallback);
+    let (width, height) = icon_size(view_box, &size, width.as_deref(), height.as_deref());
+    let transform = icon_transform(
+        view_box,
+        rotate,
+        mirrored,
+        flip_vertical,
+        transform.as_deref(),
+    );
+    let markup = transform_group(&markup, transform.as_deref());
+
+    rsx! {
+        svg {
+            xmlns: "http://www.w3.org/2000/svg",
+            role: icon_role(labelled),
+            "aria-hidden": icon_aria_hidden(labelled),
+            "aria-label": title,
+            width: width,
+            height: height,
+            fill: "{color}",
+            color: "{color}",
+            view_box: view_box.to_string(),
+            preserve_aspect_ratio: preserve_aspect_ratio,
+            class: animation.class(),
+            style: css,
+            dangerous_inner_html: markup,
+        }
+    }
+}
+
+/// Renders a hidden sprite sheet with a `<symbol>` for each of the given icons
+/// and styles, for icons rendered with `sprite: true` to point to.
+#[component]
+pub fn SpriteSheet(icons: Vec<(IconData, IconStyle)>) -> Element {
+    rsx! {
+        svg {
+            xmlns: "http://www.w3.org/2000/svg",
+            "aria-hidden": "true",
+            style: SPRITE_SHEET_STYLE,
+            dangerous_inner_html: render_symbols(icons),
+        }
+    }
+}
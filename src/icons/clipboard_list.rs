@@ -1,2 +0,0 @@
-#![doc = r" GENERATED FILE"]
-pub const CLIPBOARD_LIST : & crate :: IconStyleData = & crate :: IconStyleData (["" , "" , "" , "" , "" , "<g transform=\"scale(14.222222)\"><title>clipboard list fill duo</title><g fill=\"currentColor\" class=\"nc-icon-wrapper\"><path d=\"M5.25 2C3.73079 2 2.5 3.23079 2.5 4.75V14.25C2.5 15.7692 3.73079 17 5.25 17H12.75C14.2692 17 15.5 15.7692 15.5 14.25V4.75C15.5 3.23079 14.2692 2 12.75 2H5.25Z\" fill-opacity=\"0.4\" data-color=\"color-2\"></path> <path fill-rule=\"evenodd\" clip-rule=\"evenodd\" d=\"M5.5 2.25C5.5 1.28379 6.28379 0.5 7.25 0.5H10.75C11.7162 0.5 12.5 1.28379 12.5 2.25C12.5 3.21621 11.7162 4 10.75 4H7.25C6.28379 4 5.5 3.21621 5.5 2.25Z\"></path> <path fill-rule=\"evenodd\" clip-rule=\"evenodd\" d=\"M5.5 11.75C5.5 11.3358 5.83579 11 6.25 11H8.75C9.16421 11 9.5 11.3358 9.5 11.75C9.5 12.1642 9.16421 12.5 8.75 12.5H6.25C5.83579 12.5 5.5 12.1642 5.5 11.75Z\"></path> <path fill-rule=\"evenodd\" clip-rule=\"evenodd\" d=\"M5.5 8.75C5.5 8.33579 5.83579 8 6.25 8H8.75C9.16421 8 9.5 8.33579 9.5 8.75C9.5 9.16421 9.16421 9.5 8.75 9.5H6.25C5.83579 9.5 5.5 9.16421 5.5 8.75Z\"></path> <path fill-rule=\"evenodd\" clip-rule=\"evenodd\" d=\"M10.5 11.75C10.5 11.3358 10.8358 11 11.25 11H11.75C12.1642 11 12.5 11.3358 12.5 11.75C12.5 12.1642 12.1642 12.5 11.75 12.5H11.25C10.8358 12.5 10.5 12.1642 10.5 11.75Z\"></path> <path fill-rule=\"evenodd\" clip-rule=\"evenodd\" d=\"M10.5 8.75C10.5 8.33579 10.8358 8 11.25 8H11.75C12.1642 8 12.5 8.33579 12.5 8.75C12.5 9.16421 12.1642 9.5 11.75 9.5H11.25C10.8358 9.5 10.5 9.16421 10.5 8.75Z\"></path></g></g>"]) ;
@@ -1,2 +0,0 @@
-#![doc = r" GENERATED FILE"]
-pub const COPY_BOLD : & crate :: IconStyleData = & crate :: IconStyleData (["" , "" , "" , "<g transform=\"scale(12.8)\"><title>clone 2 fill</title><g fill=\"currentColor\" class=\"nc-icon-wrapper\"><path d=\"m7,13h-2c-1.105,0-2-.895-2-2v-6c0-1.105.895-2,2-2h6c1.105,0,2,.895,2,2v2\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\" data-color=\"color-2\"></path><rect x=\"7\" y=\"7\" width=\"10\" height=\"10\" rx=\"2\" ry=\"2\" transform=\"translate(24 24) rotate(180)\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></rect></g></g>" , "" , ""]) ;
@@ -1,2 +0,0 @@
-#![doc = r" GENERATED FILE"]
-pub const EXTERNAL_LINK_BOLD : & crate :: IconStyleData = & crate :: IconStyleData (["" , "" , "" , "<g transform=\"scale(12.8)\"><title>external link fill</title><g fill=\"currentColor\" class=\"nc-icon-wrapper\"><polyline points=\"12 12 12 8 8 8\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\" data-color=\"color-2\"></polyline><line x1=\"3\" y1=\"17\" x2=\"12\" y2=\"8\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\" data-color=\"color-2\"></line><path d=\"m7.95,17h5.05c1.657,0,3-1.343,3-3V6c0-1.657-1.343-3-3-3h-6c-1.657,0-3,1.343-3,3v5.05\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></path></g></g>" , "" , ""]) ;
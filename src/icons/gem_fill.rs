@@ -1,2 +0,0 @@
-#![doc = r" GENERATED FILE"]
-pub const GEM_FILL : & crate :: IconStyleData = & crate :: IconStyleData (["" , "" , "" , "<g transform=\"scale(12.8)\"><title>gem fill</title><g fill=\"currentColor\" class=\"nc-icon-wrapper\"><path d=\"m16.5,7.5H3.5c-.4141,0-.75-.3359-.75-.75s.3359-.75.75-.75h13c.4141,0,.75.3359.75.75s-.3359.75-.75.75Z\" fill=\"currentColor\" stroke-width=\"0\" data-color=\"color-2\"></path><path d=\"m9.9995,17.75c-.3223,0-.6206-.2095-.7178-.5347l-3-10c-.0552-.1826-.0381-.3799.0474-.5508l2-4c.1855-.3701.6357-.52,1.0063-.3354.3706.1855.5205.6357.3354,1.0063l-1.8672,3.7339,2.9146,9.7153c.1191.3965-.106.8145-.5029.9336-.0718.0215-.144.0317-.2158.0317Z\" fill=\"currentColor\" stroke-width=\"0\" data-color=\"color-2\"></path><path d=\"m10.0005,17.75c-.0718,0-.144-.0103-.2158-.0317-.397-.1191-.6221-.5371-.5029-.9336l2.9146-9.7153-1.8672-3.7339c-.1851-.3706-.0352-.8208.3354-1.0063.3711-.1846.8218-.0347,1.0063.3354l2,4c.0854.1709.1025.3682.0474.5508l-3,10c-.0972.3252-.3955.5347-.7178.5347Z\" fill=\"currentColor\" stroke-width=\"0\" data-color=\"color-2\"></path><path d=\"m16.5469,5.9107c.5606.6407.6148,1.5801.1316,2.281l-5.712,8.2855c-.4496.7084-1.4833.7084-1.9328,0L3.3215,8.1917c-.4832-.7009-.429-1.6403.1316-2.281l1.9492-2.2277c.3798-.434.9284-.683,1.5052-.683h6.1849c.5767,0,1.1254.249,1.5052.683l1.9492,2.2277Z\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></path></g></g>" , "" , ""]) ;
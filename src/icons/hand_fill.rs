@@ -1,2 +0,0 @@
-#![doc = r" GENERATED FILE"]
-pub const HAND_FILL : & crate :: IconStyleData = & crate :: IconStyleData (["" , "" , "" , "<g transform=\"scale(12.8)\"><title>hand 2 fill</title><g fill=\"currentColor\" class=\"nc-icon-wrapper\"><line x1=\"16\" y1=\"5\" x2=\"16\" y2=\"11\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></line><line x1=\"13\" y1=\"4\" x2=\"13\" y2=\"12\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></line><line x1=\"10\" y1=\"3\" x2=\"10\" y2=\"13\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></line><line x1=\"7\" y1=\"4\" x2=\"7\" y2=\"12\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></line><line x1=\"7.384\" y1=\"15.082\" x2=\"3.5\" y2=\"10\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2.5\"></line><path d=\"m7,10.5v1.5l-.793,1.43c.615,2.065,2.528,3.57,4.793,3.57,2.761,0,5-2.239,5-5v-1.5H7Z\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\" fill=\"currentColor\"></path></g></g>" , "" , ""]) ;
@@ -1,36 +0,0 @@
-#[cfg(any(feature = "core", feature = "ui", feature = "business-finance"))]
-#[doc(hidden)]
-mod clipboard_list;
-#[cfg(any(feature = "core", feature = "ui", feature = "business-finance"))]
-#[doc(hidden)]
-pub use clipboard_list::*;
-#[cfg(any(feature = "ui", feature = "micro-bold"))]
-#[doc(hidden)]
-mod copy_bold;
-#[cfg(any(feature = "ui", feature = "micro-bold"))]
-#[doc(hidden)]
-pub use copy_bold::*;
-#[cfg(any(feature = "core", feature = "flags", feature = "micro-bold"))]
-#[doc(hidden)]
-mod external_link_bold;
-#[cfg(any(feature = "core", feature = "flags", feature = "micro-bold"))]
-#[doc(hidden)]
-pub use external_link_bold::*;
-#[cfg(any(feature = "ui", feature = "micro-bold"))]
-#[doc(hidden)]
-mod gem_fill;
-#[cfg(any(feature = "ui", feature = "micro-bold"))]
-#[doc(hidden)]
-pub use gem_fill::*;
-#[cfg(any(feature = "ui", feature = "micro-bold"))]
-#[doc(hidden)]
-mod hand_fill;
-#[cfg(any(feature = "ui", feature = "micro-bold"))]
-#[doc(hidden)]
-pub use hand_fill::*;
-#[cfg(any(feature = "ui", feature = "micro-bold"))]
-#[doc(hidden)]
-mod square_bars_fill;
-#[cfg(any(feature = "ui", feature = "micro-bold"))]
-#[doc(hidden)]
-pub use square_bars_fill::*;
@@ -1,2 +0,0 @@
-#![doc = r" GENERATED FILE"]
-pub const SQUARE_BARS_FILL : & crate :: IconStyleData = & crate :: IconStyleData (["" , "" , "" , "<g transform=\"scale(12.8)\"><title>square bars fill</title><g fill=\"currentColor\" class=\"nc-icon-wrapper\"><rect x=\"3\" y=\"3\" width=\"14\" height=\"14\" rx=\"3\" ry=\"3\" transform=\"translate(0 20) rotate(-90)\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\"></rect><line x1=\"13\" y1=\"8\" x2=\"7\" y2=\"8\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\" data-color=\"color-2\"></line><line x1=\"13\" y1=\"12\" x2=\"7\" y2=\"12\" fill=\"none\" stroke=\"currentColor\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-width=\"2\" data-color=\"color-2\"></line></g></g>" , "" , ""]) ;
@@ -4,68 +4,196 @@
 #![doc = r""]
 #![doc = r" ```"]
 #![doc = r" use leptos::prelude::*;"]
-#![doc = r" use phosphor_leptos::{Icon, IconStyle, HORSE, HEART, CUBE};"]
+#![doc = r" use lumo_icons::{Icon, IconStyle, CLIPBOARD_LIST, COPY_BOLD, GEM_FILL};"]
 #![doc = r""]
 #![doc = r" #[component]"]
 #![doc = r" fn MyComponent() -> impl IntoView {"]
 #![doc = r"     view! {"]
-#![doc = r"         <Icon icon=HORSE />"]
-#![doc = r##"         <Icon icon=HEART color="#AE2983" style=IconStyle::Fill size="32px" />"##]
-#![doc = r#"         <Icon icon=CUBE color="teal" style=IconStyle::Duotone />"#]
+#![doc = r"         <Icon icon=GEM_FILL style=IconStyle::MicroBold />"]
+#![doc = r##"         <Icon icon=COPY_BOLD color="#AE2983" style=IconStyle::MicroBold size="32px" />"##]
+#![doc = r#"         <Icon icon=CLIPBOARD_LIST color="teal" style=IconStyle::Ui />"#]
 #![doc = r"     }"]
 #![doc = r" }"]
 #![doc = r" ```"]
-use leptos::{prelude::*, text_prop::TextProp};
-mod icons;
-pub use icons::*;
-#[doc = r" An icon's style."]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum IconStyle {
-    Core,
-    Flags,
-    Glass,
-    MicroBold,
-    SocialMedia,
-    Ui,
-}
-#[doc = r" The SVG path data for all styles of a particular icon."]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct IconStyleData([&'static str; 6usize]);
-impl IconStyleData {
-    pub fn get(&self, style: IconStyle) -> &'static str {
-        match style {
-            IconStyle::Core => self.0[0usize],
-            IconStyle::Flags => self.0[1usize],
-            IconStyle::Glass => self.0[2usize],
-            IconStyle::MicroBold => self.0[3usize],
-            IconStyle::SocialMedia => self.0[4usize],
-            IconStyle::Ui => self.0[5usize],
-        }
-    }
+#![doc = r""]
+#![doc = r" Enable the `csr`, `hydrate` or `ssr` feature to match the rendering mode"]
+#![doc = r" of your Leptos application. The icon data itself lives in"]
+#![doc = r" [`lumo_icons_core`], which is re-exported here; turn off the default"]
+#![doc = r" `leptos` feature to use it without Leptos."]
+#![doc = r""]
+#![doc = r" The `dioxus` and `yew` features add `dioxus::Icon` and `yew::Icon`"]
+#![doc = r" components with the same props, for apps built with those frameworks."]
+#![doc = r""]
+#![doc = r" With the `trusted-types` feature, the Leptos components build the icons'"]
+#![doc = r" elements one by one instead of assigning `inner_html`."]
+#[cfg(feature = "leptos")]
+use leptos::{attr::custom::custom_attribute, prelude::*, text_prop::TextProp};
+pub use lumo_icons_core::*;
+#[cfg(feature = "leptos")]
+mod picker;
+#[cfg(feature = "leptos")]
+pub use picker::*;
+#[cfg(feature = "leptos")]
+mod sprite;
+#[cfg(feature = "leptos")]
+pub use sprite::*;
+#[doc = r" Calls the `elements!` macro in scope with every element the generator"]
+#[doc = r" lets through, as `(name, constructor)` pairs of the `leptos::svg` module."]
+#[cfg(all(feature = "leptos", feature = "trusted-types"))]
+macro_rules! with_svg_elements {
+    () => {
+        elements!(
+            ("svg", svg),
+            ("g", g),
+            ("defs", defs),
+            ("symbol", symbol),
+            ("use", r#use),
+            ("path", path),
+            ("rect", rect),
+            ("circle", circle),
+            ("ellipse", ellipse),
+            ("line", line),
+            ("polyline", polyline),
+            ("polygon", polygon),
+            ("text", text),
+            ("tspan", tspan),
+            ("textPath", textPath),
+            ("linearGradient", linearGradient),
+            ("radialGradient", radialGradient),
+            ("stop", stop),
+            ("clipPath", clipPath),
+            ("mask", mask),
+            ("pattern", pattern),
+            ("marker", marker),
+            ("filter", filter),
+            ("feBlend", feBlend),
+            ("feColorMatrix", feColorMatrix),
+            ("feComposite", feComposite),
+            ("feDropShadow", feDropShadow),
+            ("feFlood", feFlood),
+            ("feGaussianBlur", feGaussianBlur),
+            ("feMerge", feMerge),
+            ("feMergeNode", feMergeNode),
+            ("feMorphology", feMorphology),
+            ("feOffset", feOffset)
+        )
+    };
 }
-pub type IconData = &'static IconStyleData;
+#[cfg(any(feature = "leptos", feature = "dioxus", feature = "yew"))]
+mod animation;
+#[cfg(any(feature = "leptos", feature = "dioxus", feature = "yew"))]
+mod diagnostics;
+#[cfg(feature = "dioxus")]
+pub mod dioxus;
+#[cfg(all(feature = "leptos", feature = "trusted-types"))]
+mod nodes;
+#[cfg(feature = "yew")]
+pub mod yew;
+#[cfg(feature = "leptos")]
 #[component]
 pub fn Icon(
     icon: IconData,
     # [prop (into , default = Signal :: stored (IconStyle :: Core))] style: Signal<IconStyle>,
     # [prop (into , default = TextProp :: from ("1em"))] size: TextProp,
+    #[prop(optional, into)] width: Option<TextProp>,
+    #[prop(optional, into)] height: Option<TextProp>,
+    #[prop(optional, into)] preserve_aspect_ratio: Option<TextProp>,
     # [prop (into , default = TextProp :: from ("currentColor"))] color: TextProp,
+    #[prop(optional, into)] secondary_color: Option<TextProp>,
+    #[prop(optional, into)] secondary_opacity: Option<Signal<f32>>,
     # [prop (into , default = Signal :: stored (false))] mirrored: Signal<bool>,
+    # [prop (into , default = Signal :: stored (false))] flip_vertical: Signal<bool>,
+    # [prop (into , default = Signal :: stored (0.0))] rotate: Signal<f32>,
+    #[prop(optional, into)] transform: Option<TextProp>,
+    # [prop (into , default = Signal :: stored (IconAnimation :: None))] animation: Signal<
+        IconAnimation,
+    >,
+    #[prop(optional, into)] animation_speed: Option<Signal<f32>>,
+    #[prop(optional, into)] animation_iterations: Option<Signal<u32>>,
+    #[prop(optional, into)] title: Option<TextProp>,
+    #[prop(optional)] fallback: StyleFallback,
+    #[prop(optional)] sprite: bool,
 ) -> impl IntoView {
-    let html = move || icon.get(style.get());
-    let transform = move || mirrored.get().then_some("scale(-1, 1)");
-    let height = size.clone();
+    let sprites = sprite.then(use_context::<sprite::SpriteRegistry>).flatten();
+    if sprite && sprites.is_none() {
+        diagnostics::warn_missing_sprite_sheet(icon);
+    }
+    let content = move || {
+        let style = style.get();
+        diagnostics::warn_missing_style(icon, style, fallback);
+        let transform = transform.as_ref().map(TextProp::get);
+        let transform = icon_transform(
+            icon.view_box_or_fallback(style, fallback),
+            rotate.get(),
+            mirrored.get(),
+            flip_vertical.get(),
+            transform.as_deref(),
+        );
+        #[cfg(not(feature = "trusted-types"))]
+        let content = {
+            let markup = match sprites {
+                Some(sprites) => icon
+                    .resolve_style(style, fallback)
+                    .map(|style| sprites.use_markup(icon, style))
+                    .unwrap_or_default(),
+                None => icon.get_or_fallback(style, fallback).to_string(),
+            };
+            transform_group(&markup, transform.as_deref())
+        };
+        #[cfg(feature = "trusted-types")]
+        let content = nodes::icon_view(icon, style, fallback, sprites, transform);
+        content
+    };
+    let view_box = move || icon.view_box_or_fallback(style.get(), fallback).to_string();
+    let dimensions = Signal::derive(move || {
+        let width = width.as_ref().map(TextProp::get);
+        let height = height.as_ref().map(TextProp::get);
+        icon_size(
+            icon.view_box_or_fallback(style.get(), fallback),
+            &size.get(),
+            width.as_deref(),
+            height.as_deref(),
+        )
+    });
+    let preserve_aspect_ratio = move || preserve_aspect_ratio.as_ref().map(TextProp::get);
     let color_attr = color.clone();
-    view! {
+    let labelled = title.is_some();
+    let label = move || title.as_ref().map(TextProp::get);
+    Effect::new(move |_| {
+        if animation.get() != IconAnimation::None {
+            animation::inject_stylesheet();
+        }
+    });
+    let class = move || animation.get().class();
+    let icon_style = move || {
+        let color = secondary_color.as_ref().map(TextProp::get);
+        icon_style(
+            color.as_deref(),
+            secondary_opacity.map(|opacity| opacity.get()),
+            animation_speed.map(|speed| speed.get()),
+            animation_iterations.map(|iterations| iterations.get()),
+        )
+    };
+    let svg = view! {
         <svg
             xmlns="http://www.w3.org/2000/svg"
-            width=move || size.get()
-            height=move || height.get()
+            role=icon_role(labelled)
+            aria-hidden=icon_aria_hidden(labelled)
+            aria-label=label
+            width=move || dimensions.get().0
+            height=move || dimensions.get().1
             fill=move || color.get()
             color=move || color_attr.get()
-            transform=transform
-            viewBox=concat!("0 0 ", 256i32, " ", 256i32)
-            inner_html=html
+            viewBox=view_box
+            preserveAspectRatio=preserve_aspect_ratio
+            style=icon_style
         />
     }
+    .into_inner()
+    .add_any_attr(custom_attribute("class", class));
+    #[cfg(not(feature = "trusted-types"))]
+    let svg = svg.inner_html(content);
+    #[cfg(feature = "trusted-types")]
+    let svg = svg.child(content);
+    svg
 }
@@ -0,0 +1,117 @@
+//! Building icons element by element from their [`SvgNode`] trees, so that no
+//! HTML strings are assigned to the page when it enforces Trusted Types.
+
+use crate::{
+    sprite::SpriteRegistry, sprite_use_attributes, IconData, IconStyle, StyleFallback, SvgNode,
+};
+use leptos::{
+    attr::{
+        any_attribute::{AnyAttribute, IntoAnyAttribute},
+        custom::custom_attribute,
+        AttributeValue,
+    },
+    prelude::*,
+    svg,
+};
+
+/// Renders an SVG element with the given attributes and children.
+fn element_view(name: &str, attributes: Vec<AnyAttribute>, children: Vec<AnyView>) -> AnyView {
+    // Shapes have no children, and leaving them out spares them the marker an
+    // empty list of children renders as.
+    macro_rules! element {
+        ($element:expr) => {
+            if children.is_empty() {
+                $element.add_any_attr(attributes).into_any()
+            } else {
+                $element.add_any_attr(attributes).child(children).into_any()
+            }
+        };
+    }
+    macro_rules! elements {
+        ($(($name:literal, $element:ident)),* $(,)?) => {
+            match name {
+                $($name => element!(svg::$element()),)*
+                _ => {
+                    // The generator lists every element it lets through, so
+                    // this only happens if the two get out of step.
+                    #[cfg(debug_assertions)]
+                    panic!("lumo-icons: <{name}> can't be built node by node");
+                    #[cfg(not(debug_assertions))]
+                    ().into_any()
+                }
+            }
+        };
+    }
+
+    with_svg_elements!()
+}
+
+fn attributes<V>(attributes: impl IntoIterator<Item = (&'static str, V)>) -> Vec<AnyAttribute>
+where
+    V: AttributeValue + Send + 'static,
+{
+    attributes
+        .into_iter()
+        .map(|(name, value)| custom_attribute(name, value).into_any_attr())
+        .collect()
+}
+
+/// Renders a tree of nodes.
+fn nodes_view(nodes: &'static [SvgNode]) -> Vec<AnyView> {
+    nodes
+        .iter()
+        .map(|node| match *node {
+            SvgNode::Element {
+                name,
+                attributes: attrs,
+                children,
+            } => element_view(
+                name,
+                attributes(attrs.iter().copied()),
+                nodes_view(children),
+            ),
+            SvgNode::Text(text) => text.into_any(),
+        })
+        .collect()
+}
+
+/// The content of an `Icon`: its elements, or a `<use>` pointing to them in the
+/// sprite sheet, wrapped in a group with the icon's transform if it has one.
+pub(crate) fn icon_view(
+    icon: IconData,
+    style: IconStyle,
+    fallback: StyleFallback,
+    sprites: Option<SpriteRegistry>,
+    transform: Option<String>,
+) -> AnyView {
+    let content = match sprites {
+        Some(sprites) => icon
+            .resolve_style(style, fallback)
+            .map(|style| {
+                sprites.register(icon, style);
+                let attributes = attributes(sprite_use_attributes(icon, style));
+                vec![element_view("use", attributes, Vec::new())]
+            })
+            .unwrap_or_default(),
+        None => nodes_view(icon.nodes_or_fallback(style, fallback)),
+    };
+
+    match transform {
+        Some(transform) => element_view("g", attributes([("transform", transform)]), content),
+        None => content.into_any(),
+    }
+}
+
+/// The `<symbol>`s of the given icons in a sprite sheet.
+pub(crate) fn symbols_view(icons: &[(IconData, IconStyle)]) -> Vec<AnyView> {
+    icons
+        .iter()
+        .map(|&(icon, style)| {
+            let attributes = attributes([
+                ("id", icon.symbol_id(style)),
+                ("viewBox", icon.view_box(style).to_string()),
+            ]);
+            element_view("symbol", attributes, nodes_view(icon.nodes(style)))
+        })
+        .collect()
+}
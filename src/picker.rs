@@ -0,0 +1,162 @@
+//! A searchable grid for picking an icon out of the catalog.
+
+use crate::{search_icons, Icon, IconData, IconStyle, ICONS};
+use leptos::{prelude::*, text_prop::TextProp};
+
+/// Lets the user search the enabled icons, narrow them down by category or
+/// set, switch between styles and pick one.
+///
+/// The kebab-case name of the picked icon is written to `selected` and passed
+/// to `on_select`; it can be turned back into an icon with
+/// [`icon_by_name`](crate::icon_by_name).
+///
+/// The markup only carries `lumo-icon-picker*` class names and ARIA state, so
+/// it can be styled to fit the surrounding application.
+#[component]
+pub fn IconPicker(
+    /// The name of the selected icon, if any.
+    #[prop(optional)]
+    selected: Option<RwSignal<Option<String>>>,
+    /// Called with the name of an icon whenever one is picked.
+    #[prop(optional, into)]
+    on_select: Option<Callback<String>>,
+    /// The style the picker starts out showing, by default the first style any
+    /// enabled icon is drawn in.
+    #[prop(optional)]
+    style: Option<IconStyle>,
+    /// The size of the icons in the grid.
+    #[prop(into, default = TextProp::from("24px"))]
+    size: TextProp,
+) -> impl IntoView {
+    let selected = selected.unwrap_or_else(|| RwSignal::new(None));
+    let query = RwSignal::new(String::new());
+    let category = RwSignal::new(None);
+    let set = RwSignal::new(None);
+
+    // Only offer styles that at least one enabled icon is drawn in.
+    let styles: Vec<IconStyle> = IconStyle::ALL
+        .into_iter()
+        .filter(|&s| ICONS.iter().any(|icon| !icon.get(s).is_empty()))
+        .collect();
+    let style = RwSignal::new(style.or(styles.first().copied()).unwrap_or_default());
+    let categories = unique(ICONS.iter().flat_map(|icon| icon.meta().categories));
+    let sets = unique(ICONS.iter().flat_map(|icon| icon.meta().sets));
+
+    let results = Memo::new(move |_| {
+        let style = style.get();
+        let category = category.get();
+        let set = set.get();
+        query
+            .with(|query| search_icons(query))
+            .into_iter()
+            .map(|result| result.icon)
+            .filter(|icon| !icon.get(style).is_empty())
+            .filter(|icon| category.is_none_or(|c| icon.meta().categories.contains(&c)))
+            .filter(|icon| set.is_none_or(|s| icon.meta().sets.contains(&s)))
+            .collect::<Vec<IconData>>()
+    });
+
+    let select = move |icon: IconData| {
+        let name = icon.meta().name.to_string();
+        selected.set(Some(name.clone()));
+        if let Some(on_select) = on_select {
+            on_select.run(name);
+        }
+    };
+
+    view! {
+        <div class="lumo-icon-picker">
+            <input
+                type="search"
+                class="lumo-icon-picker-search"
+                placeholder="Search icons"
+                aria-label="Search icons"
+                prop:value=move || query.get()
+                on:input=move |ev| query.set(event_target_value(&ev))
+            />
+            <div class="lumo-icon-picker-styles" role="group" aria-label="Style">
+                {styles
+                    .into_iter()
+                    .map(|s| {
+                        view! {
+                            <button
+                                type="button"
+                                class="lumo-icon-picker-chip"
+                                aria-pressed=move || (style.get() == s).to_string()
+                                on:click=move |_| style.set(s)
+                            >
+                                {s.name()}
+                            </button>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+            <FilterChips label="Category" options=categories filter=category />
+            <FilterChips label="Set" options=sets filter=set />
+            <div class="lumo-icon-picker-grid" role="listbox" aria-label="Icons">
+                <For
+                    each=move || results.get()
+                    key=|icon| icon.meta().name
+                    children=move |icon| {
+                        let name = icon.meta().name;
+                        view! {
+                            <button
+                                type="button"
+                                class="lumo-icon-picker-icon"
+                                role="option"
+                                title=name
+                                aria-label=name
+                                aria-selected=move || {
+                                    selected.with(|s| s.as_deref() == Some(name)).to_string()
+                                }
+                                on:click=move |_| select(icon)
+                            >
+                                <Icon icon=icon style=style size=size.clone() />
+                            </button>
+                        }
+                    }
+                />
+            </div>
+        </div>
+    }
+}
+
+/// A row of toggle buttons that narrows the picker down to a single value.
+#[component]
+fn FilterChips(
+    label: &'static str,
+    options: Vec<&'static str>,
+    filter: RwSignal<Option<&'static str>>,
+) -> impl IntoView {
+    view! {
+        <div class="lumo-icon-picker-filters" role="group" aria-label=label>
+            {options
+                .into_iter()
+                .map(|option| {
+                    view! {
+                        <button
+                            type="button"
+                            class="lumo-icon-picker-chip"
+                            aria-pressed=move || (filter.get() == Some(option)).to_string()
+                            on:click=move |_| {
+                                filter.update(|f| {
+                                    *f = if *f == Some(option) { None } else { Some(option) };
+                                })
+                            }
+                        >
+                            {option}
+                        </button>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
+
+/// The distinct values, sorted alphabetically.
+fn unique<'a>(values: impl Iterator<Item = &'a &'static str>) -> Vec<&'static str> {
+    let mut values: Vec<_> = values.copied().collect();
+    values.sort_unstable();
+    values.dedup();
+    values
+}
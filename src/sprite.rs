@@ -0,0 +1,89 @@
+//! The Leptos sprite sheet, which renders every icon used below it only once.
+
+#[cfg(feature = "trusted-types")]
+use crate::nodes;
+#[cfg(not(feature = "trusted-types"))]
+use crate::{render_sprite_use, render_symbols};
+use crate::{IconData, IconStyle, SPRITE_SHEET_STYLE};
+use leptos::prelude::*;
+
+/// The icons and styles rendered in sprite mode below a [`SpriteSheet`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SpriteRegistry(RwSignal<Vec<(IconData, IconStyle)>>);
+
+impl SpriteRegistry {
+    /// Adds an icon to the sheet, unless it's already on it.
+    pub(crate) fn register(self, icon: IconData, style: IconStyle) {
+        if !self
+            .0
+            .with_untracked(|icons| icons.contains(&(icon, style)))
+        {
+            self.0.update(|icons| icons.push((icon, style)));
+        }
+    }
+
+    /// Adds an icon to the sheet and returns the `<use>` markup pointing to it.
+    #[cfg(not(feature = "trusted-types"))]
+    pub(crate) fn use_markup(self, icon: IconData, style: IconStyle) -> String {
+        self.register(icon, style);
+        render_sprite_use(icon, style)
+    }
+}
+
+/// Renders its children followed by a hidden sprite sheet, holding a `<symbol>`
+/// for every icon and style they render with `sprite=true`.
+///
+/// The sheet comes after the children so that it's complete by the time it is
+/// rendered on the server, and hydrates to the same markup.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use lumo_icons::{Icon, SpriteSheet, COPY_BOLD};
+///
+/// #[component]
+/// fn Rows() -> impl IntoView {
+///     view! {
+///         <SpriteSheet>
+///             {(0..500).map(|_| view! { <Icon icon=COPY_BOLD sprite=true /> }).collect_view()}
+///         </SpriteSheet>
+///     }
+/// }
+/// ```
+#[component]
+pub fn SpriteSheet(children: Children) -> impl IntoView {
+    let registry = SpriteRegistry(RwSignal::new(Vec::new()));
+    provide_context(registry);
+
+    let children = children();
+
+    #[cfg(not(feature = "trusted-types"))]
+    let sheet = {
+        let symbols = move || {
+            registry
+                .0
+                .with(|icons| render_symbols(icons.iter().copied()))
+        };
+        view! {
+            <svg
+                xmlns="http://www.w3.org/2000/svg"
+                aria-hidden="true"
+                style=SPRITE_SHEET_STYLE
+                inner_html=symbols
+            />
+        }
+    };
+    #[cfg(feature = "trusted-types")]
+    let sheet = {
+        let symbols = move || registry.0.with(|icons| nodes::symbols_view(icons));
+        view! {
+            <svg xmlns="http://www.w3.org/2000/svg" aria-hidden="true" style=SPRITE_SHEET_STYLE>
+                {symbols}
+            </svg>
+        }
+    };
+
+    view! {
+        {children}
+        {sheet}
+    }
+}
@@ -0,0 +1,117 @@
+//! The `Icon` and `SpriteSheet` components for [Yew](https://yew.rs).
+
+use crate::{
+    animation, diagnostics, render_sprite_sheet, render_svg, IconAnimation, IconData, IconStyle,
+    RenderOptions, StyleFallback,
+};
+use ::yew::prelude::*;
+
+/// The props of [`Icon`], matching those of the Leptos `Icon`.
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct IconProps {
+    pub icon: IconData,
+    #[prop_or_default]
+    pub style: IconStyle,
+    #[prop_or(AttrValue::Static("1em"))]
+    pub size: AttrValue,
+    #[prop_or_default]
+    pub width: Option<AttrValue>,
+    #[prop_or_default]
+    pub height: Option<AttrValue>,
+    #[prop_or_default]
+    pub preserve_aspect_ratio: Option<AttrValue>,
+    #[prop_or(AttrValue::Static("currentColor"))]
+    pub color: AttrValue,
+    #[prop_or_default]
+    pub secondary_color: Option<AttrValue>,
+    #[prop_or_default]
+    pub secondary_opacity: Option<f32>,
+    #[prop_or_default]
+    pub mirrored: bool,
+    #[prop_or_default]
+    pub flip_vertical: bool,
+    #[prop_or_default]
+    pub rotate: f32,
+    #[prop_or_default]
+    pub transform: Option<AttrValue>,
+    #[prop_or_default]
+    pub animation: IconAnimation,
+    #[prop_or_default]
+    pub animation_speed: Option<f32>,
+    #[prop_or_default]
+    pub animation_iterations: Option<u32>,
+    #[prop_or_default]
+    pub title: Option<AttrValue>,
+    #[prop_or_default]
+    pub fallback: StyleFallback,
+    #[prop_or_default]
+    pub sprite: bool,
+}
+
+/// Renders an icon, with the same props and markup as the Leptos `Icon`.
+///
+/// Yew can only insert raw markup as HTML, which would put the icon's paths
+/// outside of the SVG namespace, so the whole `<svg>` is rendered by
+/// [`render_svg`] instead.
+///
+/// ```
+/// use yew::prelude::*;
+/// use lumo_icons::{yew::Icon, IconStyle, GEM_FILL};
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! { <Icon icon={GEM_FILL} style={IconStyle::MicroBold} size="32px" /> }
+/// }
+/// ```
+#[function_component]
+pub fn Icon(props: &IconProps) -> Html {
+    diagnostics::warn_missing_style(props.icon, props.style, props.fallback);
+    use_effect_with(props.animation, |&animation| {
+        if animation != IconAnimation::None {
+            animation::inject_stylesheet();
+        }
+    });
+
+    let svg = render_svg(
+        props.icon,
+        props.style,
+        &RenderOptions {
+            size: props.size.to_string(),
+            width: props.width.as_ref().map(ToString::to_string),
+            height: props.height.as_ref().map(ToString::to_string),
+            preserve_aspect_ratio: props
+                .preserve_aspect_ratio
+                .as_ref()
+                .map(ToString::to_string),
+            color: props.color.to_string(),
+            secondary_color: props.secondary_color.as_ref().map(ToString::to_string),
+            secondary_opacity: props.secondary_opacity,
+            mirrored: props.mirrored,
+            flip_vertical: props.flip_vertical,
+            rotate: props.rotate,
+            transform: props.transform.as_ref().map(ToString::to_string),
+            animation: props.animation,
+            animation_speed: props.animation_speed,
+            animation_iterations: props.animation_iterations,
+            title: props.title.as_ref().map(ToString::to_string),
+            fallback: props.fallback,
+            sprite: props.sprite,
+        },
+    );
+
+    Html::from_html_unchecked(svg.into())
+}
+
+/// The props of [`SpriteSheet`].
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct SpriteSheetProps {
+    /// The icons and styles to put on the sheet.
+    pub icons: Vec<(IconData, IconStyle)>,
+}
+
+/// Renders a hidden sprite sheet with a `<symbol>` for each of the given icons
+/// and styles, for icons rendered with `sprite={true}` to point to.
+#[function_component]
+pub fn SpriteSheet(props: &SpriteSheetProps) -> Html {
+    Html::from_html_unchecked(render_sprite_sheet(props.icons.iter().copied()).into())
+}
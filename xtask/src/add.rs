@@ -0,0 +1,269 @@
+//! Imports an SVG file into one of the style folders and records it in the
+//! metadata, so a new icon doesn't need its JSON entry written by hand.
+
+use crate::optimize::{is_editor_name, strip_children};
+use crate::svg::{self, Element};
+use crate::update::{self, ASSETS_DIR, TYPESCRIPT_EXPORT_FILE};
+use crate::{sanitize, validate::SETS};
+use convert_case::{Case, Casing};
+use serde_json::{Map, Value};
+use std::path::Path;
+use std::{fs, process};
+
+/// The codepoint of the first icon, when the metadata has none yet.
+const FIRST_CODEPOINT: u64 = 71001;
+
+/// What to record about the icon, from the command line.
+pub struct Options<'a> {
+    pub style: &'a str,
+    pub name: Option<&'a str>,
+    pub sets: &'a [String],
+    pub categories: &'a [String],
+    pub tags: &'a [String],
+    pub weight: Option<&'a str>,
+    pub release: Option<f64>,
+}
+
+fn fail(message: impl AsRef<str>) -> ! {
+    eprintln!("{}", message.as_ref());
+    process::exit(1);
+}
+
+/// Drops what the editors leave behind: titles and metadata, editor elements
+/// and attributes, and the whitespace between elements.
+fn strip(element: &mut Element) {
+    strip_children(element);
+    element.attributes.retain(|(name, _)| !is_editor_name(name));
+    element.elements_mut().for_each(strip);
+}
+
+/// Parses and checks the SVG file, returning it as a single-line document.
+fn normalize(file: &str) -> String {
+    let raw = fs::read_to_string(file).unwrap_or_else(|e| fail(format!("{file}: {e}")));
+    let mut root = svg::parse(&raw).unwrap_or_else(|e| fail(format!("{file}: {e}")));
+    match root.view_box() {
+        Ok(Some(_)) => {}
+        Ok(None) => fail(format!("{file}: {}", svg::Error::MissingViewBox)),
+        Err(e) => fail(format!("{file}: {e}")),
+    }
+
+    let problems = sanitize::check(&root);
+    if !problems.is_empty() {
+        eprintln!("{file}:");
+        for problem in problems {
+            eprintln!("  {problem}");
+        }
+        fail(format!("Refusing to add {file}: it failed the check"));
+    }
+
+    strip(&mut root);
+    svg::to_document(&root)
+}
+
+/// Appends the values missing from the list under `key`, creating it if needed.
+fn merge(entry: &mut Map<String, Value>, key: &str, values: &[String]) {
+    let list = entry.entry(key).or_insert_with(|| Value::Array(Vec::new()));
+    if let Value::Array(list) = list {
+        for value in values {
+            if !list.iter().any(|v| v.as_str() == Some(value)) {
+                list.push(Value::from(value.as_str()));
+            }
+        }
+    }
+}
+
+/// Writes the metadata the way it is laid out by hand: one field per line and
+/// lists on a single line.
+fn to_json(icons: &Map<String, Value>) -> String {
+    fn value(value: &Value) -> String {
+        match value {
+            Value::Array(items) => {
+                let items: Vec<_> = items.iter().map(|item| item.to_string()).collect();
+                format!("[{}]", items.join(", "))
+            }
+            value => value.to_string(),
+        }
+    }
+
+    let icons: Vec<_> = icons
+        .iter()
+        .map(|(name, entry)| {
+            let fields: Vec<_> = entry
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(key, field)| format!("    {}: {}", Value::from(key.as_str()), value(field)))
+                .collect();
+            format!(
+                "  {}: {{\n{}\n  }}",
+                Value::from(name.as_str()),
+                fields.join(",\n")
+            )
+        })
+        .collect();
+    format!("{{\n{}\n}}\n", icons.join(",\n"))
+}
+
+/// The release being prepared, taken to be the latest one in the metadata.
+fn latest_release(icons: &Map<String, Value>) -> f64 {
+    icons
+        .values()
+        .flat_map(|entry| ["published_in", "updated_in"].map(|key| entry.get(key)))
+        .filter_map(|version| version?.as_f64())
+        .fold(1.0, f64::max)
+}
+
+/// The codepoint after the highest one in use.
+fn next_codepoint(icons: &Map<String, Value>) -> u64 {
+    icons
+        .values()
+        .filter_map(|entry| entry.get("codepoint")?.as_u64())
+        .max()
+        .map_or(FIRST_CODEPOINT, |codepoint| codepoint + 1)
+}
+
+pub fn run(file: &str, options: &Options, precision: usize) {
+    let style_dir = format!("{ASSETS_DIR}/{}", options.style);
+    if !Path::new(&style_dir).is_dir() {
+        fail(format!(
+            "Unknown style `{}`: there is no {style_dir} folder",
+            options.style
+        ));
+    }
+    for set in options.sets {
+        if !SETS.contains(&set.as_str()) {
+            fail(format!(
+                "Unknown set `{set}`, expected one of {}",
+                SETS.join(", ")
+            ));
+        }
+    }
+
+    let icon_name = options
+        .name
+        .or_else(|| Path::new(file).file_stem().and_then(|stem| stem.to_str()))
+        .unwrap_or_else(|| fail(format!("{file}: can't name the icon after the file")))
+        .to_case(Case::Kebab);
+    let markup = normalize(file);
+
+    let typescript_export = fs::read_to_string(TYPESCRIPT_EXPORT_FILE).unwrap();
+    let Value::Object(mut icons) = serde_json::from_str(&typescript_export).unwrap() else {
+        fail(format!("{TYPESCRIPT_EXPORT_FILE} isn't a JSON object"));
+    };
+
+    // Icons are published in the release being prepared, unless another is given.
+    let release = options.release.unwrap_or_else(|| latest_release(&icons));
+
+    let is_new = !icons.contains_key(&icon_name);
+    if is_new {
+        if options.sets.is_empty() {
+            fail(format!(
+                "{icon_name} is a new icon, give the sets it is in with --sets"
+            ));
+        }
+        let codepoint = next_codepoint(&icons);
+
+        let mut entry = Map::new();
+        entry.insert("name".into(), Value::from(icon_name.as_str()));
+        entry.insert(
+            "pascal_name".into(),
+            Value::from(icon_name.to_case(Case::Pascal)),
+        );
+        entry.insert("sets".into(), Value::Array(Vec::new()));
+        entry.insert("styles".into(), Value::Array(Vec::new()));
+        if let Some(weight) = options.weight {
+            entry.insert("weight".into(), Value::from(weight));
+        }
+        entry.insert("categories".into(), Value::Array(Vec::new()));
+        entry.insert("tags".into(), Value::Array(Vec::new()));
+        entry.insert("codepoint".into(), Value::from(codepoint));
+        entry.insert("published_in".into(), Value::from(release));
+        entry.insert("updated_in".into(), Value::from(release));
+        icons.insert(icon_name.clone(), Value::Object(entry));
+    }
+
+    let Some(Value::Object(entry)) = icons.get_mut(&icon_name) else {
+        fail(format!(
+            "{icon_name}: its entry in {TYPESCRIPT_EXPORT_FILE} isn't a JSON object"
+        ));
+    };
+    merge(entry, "sets", options.sets);
+    merge(entry, "styles", &[options.style.to_string()]);
+    merge(entry, "categories", options.categories);
+    merge(entry, "tags", options.tags);
+    if let Some(weight) = options.weight {
+        entry.insert("weight".into(), Value::from(weight));
+    }
+    if !is_new {
+        entry.insert("updated_in".into(), Value::from(release));
+    }
+    let codepoint = entry.get("codepoint").and_then(Value::as_u64);
+
+    let path = format!("{style_dir}/{icon_name}.svg");
+    let replaced = Path::new(&path).exists();
+    fs::write(&path, markup).unwrap();
+    fs::write(TYPESCRIPT_EXPORT_FILE, to_json(&icons)).unwrap();
+
+    println!(
+        "{} {path}{}",
+        if replaced { "Replaced" } else { "Added" },
+        match codepoint {
+            Some(codepoint) => format!(" (codepoint {codepoint}, U+{codepoint:04X})"),
+            None => String::new(),
+        }
+    );
+
+    update::run(precision);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn icons(json: &str) -> Map<String, Value> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn writes_the_metadata_as_it_is_laid_out() {
+        let json = include_str!("../../metadata/icons.json");
+        assert_eq!(to_json(&icons(json)), json);
+    }
+
+    #[test]
+    fn merges_without_duplicates() {
+        let mut entry = icons(r#"{ "tags": ["bell", "alarm"] }"#);
+        merge(
+            &mut entry,
+            "tags",
+            &["alarm".to_string(), "ring".to_string(), "ring".to_string()],
+        );
+        merge(&mut entry, "sets", &["ui".to_string()]);
+        assert_eq!(
+            Value::Object(entry),
+            serde_json::json!({ "tags": ["bell", "alarm", "ring"], "sets": ["ui"] })
+        );
+    }
+
+    #[test]
+    fn allocates_the_codepoint_after_the_highest() {
+        assert_eq!(next_codepoint(&Map::new()), FIRST_CODEPOINT);
+        assert_eq!(
+            next_codepoint(&icons(
+                r#"{ "a": { "codepoint": 71003 }, "b": {}, "c": { "codepoint": 71001 } }"#
+            )),
+            71004
+        );
+    }
+
+    #[test]
+    fn publishes_in_the_latest_release() {
+        assert_eq!(latest_release(&Map::new()), 1.0);
+        assert_eq!(
+            latest_release(&icons(
+                r#"{ "a": { "published_in": 1.0, "updated_in": 1.2 }, "b": { "published_in": 1.1 } }"#
+            )),
+            1.2
+        );
+    }
+}
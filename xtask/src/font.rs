@@ -0,0 +1,637 @@
+use crate::update::{
+    check_assets, extract_metadata, normalize_svg, NormalizedSvg, ASSETS_DIR,
+    TYPESCRIPT_EXPORT_FILE,
+};
+use convert_case::{Case, Casing};
+use kurbo::{Affine, BezPath, CubicBez, PathEl, Point, Shape};
+use std::io::Write;
+use std::{env, fs, path::Path, process};
+use usvg::{tiny_skia_path, FillRule, Node};
+use write_fonts::tables::cmap::Cmap;
+use write_fonts::tables::glyf::{Bbox, GlyfLocaBuilder, Glyph, SimpleGlyph};
+use write_fonts::tables::head::{Flags, Head};
+use write_fonts::tables::hhea::Hhea;
+use write_fonts::tables::hmtx::{Hmtx, LongMetric};
+use write_fonts::tables::maxp::Maxp;
+use write_fonts::tables::name::{Name, NameRecord};
+use write_fonts::tables::os2::{Os2, SelectionFlags};
+use write_fonts::tables::post::Post;
+use write_fonts::types::{FWord, Fixed, GlyphId, LongDateTime, NameId, Tag, UfWord};
+use write_fonts::{FontBuilder, OffsetMarker};
+
+const UNITS_PER_EM: u16 = 1000;
+// The em box sits slightly below the baseline, so icons line up with the text around them.
+const ASCENDER: i16 = 850;
+const DESCENDER: i16 = -150;
+// TrueType outlines only have quadratic curves; this is how far the quadratic
+// approximation of a cubic may stray from it, in font units.
+const CURVE_ACCURACY: f64 = 0.5;
+
+struct IconGlyph {
+    name: String,
+    codepoint: char,
+    outline: BezPath,
+    advance: u16,
+}
+
+/// One filled area of an icon: a fill, or the outline of a stroke.
+struct Layer {
+    path: BezPath,
+    rule: FillRule,
+    opaque: bool,
+}
+
+pub fn run(style: &str, out_dir: &str, precision: usize) {
+    let style_dir = format!("{ASSETS_DIR}/{style}");
+    if !Path::new(&style_dir).is_dir() {
+        eprintln!("Unknown style `{style}`: there is no {style_dir} folder");
+        process::exit(1);
+    }
+
+    let typescript_export = fs::read_to_string(TYPESCRIPT_EXPORT_FILE).unwrap();
+    let icon_metadata = extract_metadata(&typescript_export);
+
+    let mut file_names: Vec<_> = fs::read_dir(&style_dir)
+        .unwrap()
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name.ends_with(".svg"))
+        .collect();
+    file_names.sort_unstable();
+    check_assets(&[style.to_string()], &file_names);
+
+    let mut glyphs = Vec::new();
+    for file_name in file_names {
+        let icon_name = file_name.strip_suffix(".svg").unwrap();
+        let Some(codepoint) = icon_metadata.get(icon_name).and_then(|m| m.codepoint) else {
+            eprintln!("Skipping {icon_name}: it has no codepoint in {TYPESCRIPT_EXPORT_FILE}");
+            continue;
+        };
+        let codepoint = char::from_u32(codepoint)
+            .unwrap_or_else(|| panic!("{icon_name} has an invalid codepoint {codepoint}"));
+
+        let path = format!("{style_dir}/{file_name}");
+        let svg_raw = fs::read_to_string(&path).unwrap();
+        // The same markup as the components, so glyphs don't differ from them.
+        let svg = normalize_svg(&svg_raw, precision)
+            .unwrap_or_else(|e| panic!("Error reading {path}: {e}"));
+        let (outline, advance) = glyph_outline(&glyph_document(&svg))
+            .unwrap_or_else(|e| panic!("Error reading {path}: {e}"));
+
+        glyphs.push(IconGlyph {
+            name: icon_name.to_string(),
+            codepoint,
+            outline,
+            advance,
+        });
+    }
+
+    if glyphs.is_empty() {
+        eprintln!("No icons with a codepoint in {style_dir}");
+        process::exit(1);
+    }
+
+    let family = format!("Lumo Icons {}", style.to_case(Case::Title));
+    let file_stem = format!("lumo-icons-{style}");
+    let ttf = font_template(&family, &glyphs);
+    let woff2 = woff2(&ttf);
+    let css = css_template(&family, &file_stem, style, &glyphs);
+
+    fs::create_dir_all(out_dir).unwrap();
+    fs::write(format!("{out_dir}/{file_stem}.ttf"), &ttf).expect("Error writing ttf file");
+    fs::write(format!("{out_dir}/{file_stem}.woff2"), &woff2).expect("Error writing woff2 file");
+    fs::write(format!("{out_dir}/{file_stem}.css"), css).expect("Error writing css file");
+
+    println!(
+        "Wrote {} glyphs to {out_dir}/{file_stem}.{{ttf,woff2,css}}",
+        glyphs.len()
+    );
+}
+
+/// Wraps the markup of an icon in a document usvg can read. usvg doesn't know
+/// custom properties, so the secondary color and opacity of duotone icons are
+/// replaced by their fallbacks, which is how they render unless a page sets them.
+fn glyph_document(svg: &NormalizedSvg) -> String {
+    let mut markup = String::with_capacity(svg.markup.len());
+    let mut rest = svg.markup.as_str();
+    while let Some(start) = rest.find("var(") {
+        markup.push_str(&rest[..start]);
+        rest = &rest[start + "var(".len()..];
+
+        // The fallback runs from the first comma to the closing parenthesis,
+        // and may have parentheses of its own.
+        let mut depth = 0;
+        let mut comma = None;
+        let mut end = rest.len();
+        for (i, c) in rest.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' if depth == 0 => {
+                    end = i;
+                    break;
+                }
+                ')' => depth -= 1,
+                ',' if depth == 0 && comma.is_none() => comma = Some(i),
+                _ => {}
+            }
+        }
+        if let Some(comma) = comma {
+            markup.push_str(rest[comma + 1..end].trim());
+        }
+        rest = rest.get(end + 1..).unwrap_or_default();
+    }
+    markup.push_str(rest);
+
+    let (min_x, min_y, width, height) = svg.view_box;
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{min_x} {min_y} {width} {height}">{markup}</svg>"#
+    )
+}
+
+/// Converts an SVG into a single glyph outline in font units, along with its advance width.
+fn glyph_outline(svg_raw: &str) -> Result<(BezPath, u16), usvg::Error> {
+    let tree = usvg::Tree::from_str(svg_raw, &usvg::Options::default())?;
+    let size = tree.size();
+    let (width, height) = (f64::from(size.width()), f64::from(size.height()));
+    let scale = f64::from(UNITS_PER_EM) / width.max(height);
+
+    // SVG is y-down from the top edge, fonts are y-up from the baseline. Icons
+    // that are wider than tall are centered vertically in the em box.
+    let top = f64::from(ASCENDER) - (f64::from(UNITS_PER_EM) - height * scale) / 2.0;
+    let to_font = Affine::new([scale, 0.0, 0.0, -scale, 0.0, top]);
+
+    let mut layers = Vec::new();
+    collect_layers(tree.root(), 1.0, to_font, &mut layers);
+
+    // A glyph only has one color, so the translucent secondary layer of duotone
+    // icons is kept as a backdrop with the opaque layers cut out of it.
+    let translucent = layers.iter().any(|layer| !layer.opaque);
+
+    let mut outline = BezPath::new();
+    for layer in layers {
+        let knockout = translucent && layer.opaque;
+        for contour in orient_contours(split_contours(&layer.path), layer.rule) {
+            let contour = if knockout {
+                contour.reverse_subpaths()
+            } else {
+                contour
+            };
+            outline.extend(to_quadratic(&contour));
+        }
+    }
+
+    Ok((outline, (width * scale).round() as u16))
+}
+
+fn collect_layers(group: &usvg::Group, opacity: f32, to_font: Affine, layers: &mut Vec<Layer>) {
+    let opacity = opacity * group.opacity().get();
+    for node in group.children() {
+        match node {
+            Node::Group(group) => collect_layers(group, opacity, to_font, layers),
+            Node::Path(path) if path.is_visible() => {
+                let transform = to_font * affine(path.abs_transform());
+                if let Some(fill) = path.fill() {
+                    layers.push(Layer {
+                        path: transform * bez_path(path.data()),
+                        rule: fill.rule(),
+                        opaque: opacity * fill.opacity().get() >= 1.0,
+                    });
+                }
+                if let Some(stroke) = path.stroke() {
+                    let resolution = transform.as_coeffs()[0].hypot(transform.as_coeffs()[1]);
+                    if let Some(outline) = path
+                        .data()
+                        .stroke(&stroke.to_tiny_skia(), resolution as f32)
+                    {
+                        layers.push(Layer {
+                            path: transform * bez_path(&outline),
+                            rule: FillRule::NonZero,
+                            opaque: opacity * stroke.opacity().get() >= 1.0,
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn affine(ts: tiny_skia_path::Transform) -> Affine {
+    Affine::new([ts.sx, ts.ky, ts.kx, ts.sy, ts.tx, ts.ty].map(f64::from))
+}
+
+fn bez_path(path: &tiny_skia_path::Path) -> BezPath {
+    use tiny_skia_path::PathSegment;
+
+    let point = |p: tiny_skia_path::Point| Point::new(f64::from(p.x), f64::from(p.y));
+    let mut bez = BezPath::new();
+    for segment in path.segments() {
+        match segment {
+            PathSegment::MoveTo(p) => bez.move_to(point(p)),
+            PathSegment::LineTo(p) => bez.line_to(point(p)),
+            PathSegment::QuadTo(p1, p2) => bez.quad_to(point(p1), point(p2)),
+            PathSegment::CubicTo(p1, p2, p3) => bez.curve_to(point(p1), point(p2), point(p3)),
+            PathSegment::Close => bez.close_path(),
+        }
+    }
+    bez
+}
+
+/// Splits a path into its closed subpaths; glyph contours are always closed.
+fn split_contours(path: &BezPath) -> Vec<BezPath> {
+    let mut contours: Vec<BezPath> = Vec::new();
+    for el in path.elements() {
+        match el {
+            PathEl::MoveTo(_) => contours.push(BezPath::from_vec(vec![*el])),
+            PathEl::ClosePath => {}
+            _ => contours.last_mut().unwrap().push(*el),
+        }
+    }
+    // Drop the degenerate contours that are only a point or a line.
+    contours.retain(|contour| contour.elements().len() > 2);
+    for contour in &mut contours {
+        contour.close_path();
+    }
+    contours
+}
+
+/// Rewinds the contours of one layer so that its filled area is clockwise.
+///
+/// TrueType fills with the non-zero rule, so once every layer winds the same
+/// way, overlapping layers (like a fill and its stroke) simply add up.
+fn orient_contours(mut contours: Vec<BezPath>, rule: FillRule) -> Vec<BezPath> {
+    let start = |contour: &BezPath| match contour.elements().first() {
+        Some(PathEl::MoveTo(p)) => *p,
+        _ => Point::ZERO,
+    };
+    let contains = |outer: &BezPath, inner: &BezPath| outer.winding(start(inner)) != 0;
+    let depths: Vec<usize> = contours
+        .iter()
+        .enumerate()
+        .map(|(i, contour)| {
+            contours
+                .iter()
+                .enumerate()
+                .filter(|&(j, other)| i != j && contains(other, contour))
+                .count()
+        })
+        .collect();
+
+    match rule {
+        // Nested contours alternate between filled areas and holes.
+        FillRule::EvenOdd => {
+            for (contour, depth) in contours.iter_mut().zip(&depths) {
+                let clockwise = contour.area() < 0.0;
+                if clockwise != (depth % 2 == 0) {
+                    *contour = contour.reverse_subpaths();
+                }
+            }
+        }
+        // The winding is already consistent within each outermost contour, so it
+        // is flipped as a whole when the outermost one runs counter-clockwise.
+        FillRule::NonZero => {
+            for i in 0..contours.len() {
+                if depths[i] != 0 || contours[i].area() < 0.0 {
+                    continue;
+                }
+                let outer = contours[i].clone();
+                for (j, depth) in depths.iter().enumerate() {
+                    if j == i || (*depth > 0 && contains(&outer, &contours[j])) {
+                        contours[j] = contours[j].reverse_subpaths();
+                    }
+                }
+            }
+        }
+    }
+
+    contours
+}
+
+fn to_quadratic(path: &BezPath) -> BezPath {
+    let mut quadratic = BezPath::new();
+    let mut current = Point::ZERO;
+    let mut start = Point::ZERO;
+    for el in path.elements() {
+        match *el {
+            PathEl::MoveTo(p) => {
+                quadratic.move_to(p);
+                (current, start) = (p, p);
+            }
+            PathEl::LineTo(p) => {
+                quadratic.line_to(p);
+                current = p;
+            }
+            PathEl::QuadTo(p1, p2) => {
+                quadratic.quad_to(p1, p2);
+                current = p2;
+            }
+            PathEl::CurveTo(p1, p2, p3) => {
+                for (_, _, quad) in CubicBez::new(current, p1, p2, p3).to_quads(CURVE_ACCURACY) {
+                    quadratic.quad_to(quad.p1, quad.p2);
+                }
+                current = p3;
+            }
+            PathEl::ClosePath => {
+                quadratic.close_path();
+                current = start;
+            }
+        }
+    }
+    quadratic
+}
+
+fn font_template(family: &str, glyphs: &[IconGlyph]) -> Vec<u8> {
+    // Glyph 0 is the mandatory `.notdef`, followed by the icons in name order.
+    let mut glyph_builder = GlyfLocaBuilder::new();
+    glyph_builder.add_glyph(&Glyph::Empty).unwrap();
+    let mut metrics = vec![LongMetric::new(UNITS_PER_EM, 0)];
+    let mut font_bbox: Option<Bbox> = None;
+    let (mut max_points, mut max_contours) = (0, 0);
+
+    for glyph in glyphs {
+        match SimpleGlyph::from_bezpath(&glyph.outline) {
+            Ok(simple) => {
+                let bbox = simple.bbox;
+                font_bbox = Some(font_bbox.map_or(bbox, |font_bbox| font_bbox.union(bbox)));
+                max_points = max_points.max(simple.contours.iter().map(|c| c.len()).sum());
+                max_contours = max_contours.max(simple.contours.len());
+                metrics.push(LongMetric::new(glyph.advance, bbox.x_min));
+                glyph_builder
+                    .add_glyph(&simple)
+                    .unwrap_or_else(|e| panic!("Error compiling glyph {}: {e}", glyph.name));
+            }
+            // Icons without any filled area still get a (blank) glyph.
+            Err(_) => {
+                metrics.push(LongMetric::new(glyph.advance, 0));
+                glyph_builder.add_glyph(&Glyph::Empty).unwrap();
+            }
+        }
+    }
+    let (glyf, loca, loca_format) = glyph_builder.build();
+    let bbox = font_bbox.unwrap_or_default();
+
+    // The same icons always make the same font, so the date only changes when
+    // a build asks for it through `SOURCE_DATE_EPOCH`.
+    let created = env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.trim().parse::<i64>().ok())
+        .unwrap_or(0);
+    // Font timestamps count from 1904 rather than 1970.
+    let created = LongDateTime::new(created + 2_082_844_800);
+    let head = Head {
+        font_revision: Fixed::from_f64(1.0),
+        flags: Flags::BASELINE_AT_Y_0 | Flags::LSB_AT_X_0,
+        units_per_em: UNITS_PER_EM,
+        created,
+        modified: created,
+        x_min: bbox.x_min,
+        y_min: bbox.y_min,
+        x_max: bbox.x_max,
+        y_max: bbox.y_max,
+        lowest_rec_ppem: 8,
+        index_to_loc_format: loca_format as i16,
+        ..Default::default()
+    };
+
+    let advance_width_max = metrics.iter().map(|m| m.advance).max().unwrap_or_default();
+    let hhea = Hhea {
+        ascender: FWord::new(ASCENDER),
+        descender: FWord::new(DESCENDER),
+        advance_width_max: UfWord::new(advance_width_max),
+        min_left_side_bearing: FWord::new(
+            metrics
+                .iter()
+                .map(|m| m.side_bearing)
+                .min()
+                .unwrap_or_default(),
+        ),
+        min_right_side_bearing: FWord::new(
+            glyphs
+                .iter()
+                .zip(&metrics[1..])
+                .map(|(glyph, m)| glyph.advance as i16 - m.side_bearing)
+                .min()
+                .unwrap_or_default()
+                .min(bbox.x_max),
+        ),
+        x_max_extent: FWord::new(bbox.x_max),
+        caret_slope_rise: 1,
+        number_of_h_metrics: metrics.len() as u16,
+        ..Default::default()
+    };
+
+    let maxp = Maxp {
+        num_glyphs: metrics.len() as u16,
+        max_points: Some(max_points as u16),
+        max_contours: Some(max_contours as u16),
+        max_composite_points: Some(0),
+        max_composite_contours: Some(0),
+        max_zones: Some(1),
+        max_twilight_points: Some(0),
+        max_storage: Some(0),
+        max_function_defs: Some(0),
+        max_instruction_defs: Some(0),
+        max_stack_elements: Some(0),
+        max_size_of_instructions: Some(0),
+        max_component_elements: Some(0),
+        max_component_depth: Some(0),
+    };
+
+    let codepoints = glyphs.iter().map(|glyph| glyph.codepoint as u32);
+    let first_char = codepoints.clone().min().unwrap_or_default().min(0xFFFF) as u16;
+    let last_char = codepoints.clone().max().unwrap_or_default().min(0xFFFF) as u16;
+    // Bit 57 flags characters outside the BMP, bit 60 the private use area.
+    let mut unicode_range_2 = 0;
+    if codepoints.clone().any(|c| c > 0xFFFF) {
+        unicode_range_2 |= 1 << (57 - 32);
+    }
+    if codepoints.clone().any(|c| (0xE000..=0xF8FF).contains(&c)) {
+        unicode_range_2 |= 1 << (60 - 32);
+    }
+    let os2 = Os2 {
+        x_avg_char_width: (metrics.iter().map(|m| u32::from(m.advance)).sum::<u32>()
+            / metrics.len() as u32) as i16,
+        ul_unicode_range_2: unicode_range_2,
+        ach_vend_id: Tag::new(b"LUMO"),
+        fs_selection: SelectionFlags::REGULAR | SelectionFlags::USE_TYPO_METRICS,
+        us_first_char_index: first_char,
+        us_last_char_index: last_char,
+        s_typo_ascender: ASCENDER,
+        s_typo_descender: DESCENDER,
+        us_win_ascent: ASCENDER.max(bbox.y_max) as u16,
+        us_win_descent: (-DESCENDER).max(-bbox.y_min) as u16,
+        ul_code_page_range_1: Some(1),
+        ul_code_page_range_2: Some(0),
+        sx_height: Some(0),
+        s_cap_height: Some(0),
+        us_default_char: Some(0),
+        us_break_char: Some(0x20),
+        us_max_context: Some(0),
+        ..Default::default()
+    };
+
+    let cmap = Cmap::from_mappings(
+        glyphs
+            .iter()
+            .enumerate()
+            .map(|(i, glyph)| (glyph.codepoint, GlyphId::new(i as u32 + 1))),
+    )
+    .expect("Two icons share a codepoint");
+
+    let postscript_name = family.replace(' ', "");
+    let mut name_records: Vec<_> = [
+        (NameId::FAMILY_NAME, family.to_string()),
+        (NameId::SUBFAMILY_NAME, "Regular".to_string()),
+        (NameId::UNIQUE_ID, format!("{postscript_name}-Regular")),
+        (NameId::FULL_NAME, family.to_string()),
+        (NameId::VERSION_STRING, "Version 1.000".to_string()),
+        (NameId::POSTSCRIPT_NAME, postscript_name),
+    ]
+    .into_iter()
+    .map(|(id, name)| NameRecord::new(3, 1, 0x409, id, OffsetMarker::new(name)))
+    .collect();
+    name_records.sort();
+
+    let post = Post {
+        underline_position: FWord::new(DESCENDER / 2),
+        underline_thickness: FWord::new(50),
+        ..Post::new_v2(
+            [".notdef"]
+                .into_iter()
+                .chain(glyphs.iter().map(|glyph| glyph.name.as_str())),
+        )
+    };
+
+    let hmtx = Hmtx::new(metrics, Vec::new());
+
+    FontBuilder::new()
+        .add_table(&head)
+        .and_then(|font| font.add_table(&hhea))
+        .and_then(|font| font.add_table(&maxp))
+        .and_then(|font| font.add_table(&os2))
+        .and_then(|font| font.add_table(&hmtx))
+        .and_then(|font| font.add_table(&cmap))
+        .and_then(|font| font.add_table(&loca))
+        .and_then(|font| font.add_table(&glyf))
+        .and_then(|font| font.add_table(&Name::new(name_records)))
+        .and_then(|font| font.add_table(&post))
+        .expect("Error compiling font")
+        .build()
+}
+
+/// Wraps a TrueType font in a WOFF2 container, without the optional glyf/loca transform.
+///
+/// See <https://www.w3.org/TR/WOFF2/>.
+fn woff2(ttf: &[u8]) -> Vec<u8> {
+    // The tags that WOFF2 can refer to by index, in order.
+    const KNOWN_TAGS: [&[u8; 4]; 12] = [
+        b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post", b"cvt ", b"fpgm",
+        b"glyf", b"loca",
+    ];
+    // Transform version 3 is the "null" transform for glyf and loca.
+    const NULL_TRANSFORM: u8 = 3 << 6;
+
+    fn push_base128(out: &mut Vec<u8>, mut value: u32) {
+        let mut bytes = vec![(value & 0x7F) as u8];
+        value >>= 7;
+        while value > 0 {
+            bytes.push((value & 0x7F) as u8 | 0x80);
+            value >>= 7;
+        }
+        out.extend(bytes.iter().rev());
+    }
+
+    let read_u16 = |at: usize| u16::from_be_bytes([ttf[at], ttf[at + 1]]);
+    let read_u32 = |at: usize| u32::from_be_bytes([ttf[at], ttf[at + 1], ttf[at + 2], ttf[at + 3]]);
+
+    let num_tables = read_u16(4);
+    let mut directory = Vec::new();
+    let mut table_data = Vec::new();
+    let mut sfnt_size = 12 + 16 * u32::from(num_tables);
+    for i in 0..usize::from(num_tables) {
+        let record = 12 + 16 * i;
+        let tag: [u8; 4] = ttf[record..record + 4].try_into().unwrap();
+        let offset = read_u32(record + 8) as usize;
+        let length = read_u32(record + 12);
+
+        let transform = if &tag == b"glyf" || &tag == b"loca" {
+            NULL_TRANSFORM
+        } else {
+            0
+        };
+        match KNOWN_TAGS.iter().position(|known| **known == tag) {
+            Some(index) => directory.push(transform | index as u8),
+            None => {
+                directory.push(transform | 0x3F);
+                directory.extend(tag);
+            }
+        }
+        push_base128(&mut directory, length);
+
+        table_data.extend(&ttf[offset..offset + length as usize]);
+        sfnt_size += length.next_multiple_of(4);
+    }
+
+    let mut compressed = Vec::new();
+    let params = brotli::enc::BrotliEncoderParams {
+        quality: 11,
+        mode: brotli::enc::backward_references::BrotliEncoderMode::BROTLI_MODE_FONT,
+        ..Default::default()
+    };
+    brotli::CompressorWriter::with_params(&mut compressed, 4096, &params)
+        .write_all(&table_data)
+        .expect("Error compressing font");
+
+    let length = 48 + directory.len() + compressed.len();
+    let mut woff2 = Vec::with_capacity(length);
+    woff2.extend(b"wOF2");
+    woff2.extend(0x0001_0000u32.to_be_bytes()); // TrueType flavor
+    woff2.extend((length as u32).to_be_bytes());
+    woff2.extend(num_tables.to_be_bytes());
+    woff2.extend(0u16.to_be_bytes()); // reserved
+    woff2.extend(sfnt_size.to_be_bytes());
+    woff2.extend((compressed.len() as u32).to_be_bytes());
+    woff2.extend(1u16.to_be_bytes()); // major version
+    woff2.extend(0u16.to_be_bytes()); // minor version
+    woff2.extend([0; 20]); // no metadata or private data
+    woff2.extend(directory);
+    woff2.extend(compressed);
+    woff2
+}
+
+fn css_template(family: &str, file_stem: &str, style: &str, glyphs: &[IconGlyph]) -> String {
+    let mut css = format!(
+        r#"/* GENERATED FILE! Run `cargo xtask font --style {style}` to update it. */
+
+@font-face {{
+  font-family: "{family}";
+  src: url("{file_stem}.woff2") format("woff2"), url("{file_stem}.ttf") format("truetype");
+  font-weight: normal;
+  font-style: normal;
+  font-display: block;
+}}
+
+.lumo-{style} {{
+  display: inline-block;
+  font-family: "{family}";
+  font-style: normal;
+  font-weight: normal;
+  font-variant: normal;
+  line-height: 1;
+  text-transform: none;
+  speak: never;
+  -webkit-font-smoothing: antialiased;
+  -moz-osx-font-smoothing: grayscale;
+}}
+"#
+    );
+
+    for glyph in glyphs {
+        css.push_str(&format!(
+            "\n.lumo-{}::before {{\n  content: \"\\{:x}\";\n}}\n",
+            glyph.name, glyph.codepoint as u32
+        ));
+    }
+
+    css
+}
@@ -0,0 +1,193 @@
+//! A static HTML page showing every icon in every style it is drawn in, with
+//! its metadata, for browsing the set without a Rust toolchain.
+
+use crate::update::{
+    check_assets, extract_categories, extract_metadata, normalize_svg, ASSETS_DIR,
+    TYPESCRIPT_EXPORT_FILE,
+};
+use crate::validate::{asset_styles, SETS};
+use convert_case::{Case, Casing};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::{fs, path::Path};
+
+const STYLE: &str = r#"
+:root { color-scheme: light dark; font-family: system-ui, sans-serif; }
+body { margin: 0; }
+header { position: sticky; top: 0; display: flex; flex-wrap: wrap; gap: .5rem; align-items: center; padding: 1rem; background: Canvas; border-bottom: 1px solid GrayText; }
+header h1 { margin: 0 1rem 0 0; font-size: 1.25rem; }
+header input { flex: 1; min-width: 12rem; }
+main { display: grid; grid-template-columns: repeat(auto-fill, minmax(16rem, 1fr)); gap: 1rem; padding: 1rem; }
+article { border: 1px solid GrayText; border-radius: .5rem; padding: .75rem; }
+article h2 { margin: 0 0 .5rem; font-size: 1rem; font-family: ui-monospace, monospace; }
+.styles { display: flex; flex-wrap: wrap; gap: .75rem; margin-bottom: .5rem; }
+figure { margin: 0; text-align: center; font-size: .75rem; color: GrayText; }
+figure svg { display: block; height: 48px; width: auto; max-width: 96px; margin: 0 auto .25rem; color: CanvasText; }
+dl { display: grid; grid-template-columns: auto 1fr; gap: .125rem .5rem; margin: 0; font-size: .8125rem; }
+dt { color: GrayText; }
+dd { margin: 0; }
+"#;
+
+const SCRIPT: &str = r#"
+const search = document.getElementById("search");
+const set = document.getElementById("set");
+const style = document.getElementById("style");
+const count = document.getElementById("count");
+const icons = document.querySelectorAll("article");
+
+function filter() {
+  const terms = search.value.toLowerCase().split(/\s+/).filter(Boolean);
+  let shown = 0;
+  for (const icon of icons) {
+    const visible =
+      terms.every((term) => icon.dataset.search.includes(term)) &&
+      (!set.value || icon.dataset.sets.split(" ").includes(set.value)) &&
+      (!style.value || icon.dataset.styles.split(" ").includes(style.value));
+    icon.hidden = !visible;
+    for (const figure of icon.querySelectorAll("figure")) {
+      figure.hidden = style.value !== "" && figure.dataset.style !== style.value;
+    }
+    if (visible) shown++;
+  }
+  count.textContent = `${shown} of ${icons.length} icons`;
+}
+
+for (const input of [search, set, style]) input.addEventListener("input", filter);
+filter();
+"#;
+
+/// Escapes text for HTML content and double-quoted attribute values.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn options<'a>(label: &str, values: impl IntoIterator<Item = &'a str>) -> String {
+    let mut options = format!(r#"<option value="">{label}</option>"#);
+    for value in values {
+        let value = escape(value);
+        write!(options, r#"<option value="{value}">{value}</option>"#).unwrap();
+    }
+    options
+}
+
+pub fn run(out_dir: &str, precision: usize) {
+    let typescript_export = fs::read_to_string(TYPESCRIPT_EXPORT_FILE).unwrap();
+    let (icon_sets, _) = extract_categories(&typescript_export);
+    let icon_metadata = extract_metadata(&typescript_export);
+    let assets = asset_styles();
+
+    // Only the styles something is drawn in, so every filter matches an icon.
+    let styles: Vec<_> = assets
+        .values()
+        .flatten()
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    // The page embeds the markup as is, like `Icon` does.
+    let file_names: Vec<_> = assets.keys().map(|name| format!("{name}.svg")).collect();
+    check_assets(&styles, &file_names);
+
+    let mut cards = String::new();
+    for (icon_name, icon_styles) in &assets {
+        let metadata = icon_metadata.get(icon_name);
+        let sets = icon_sets
+            .get(icon_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let tags = metadata.map(|m| m.tags.as_slice()).unwrap_or_default();
+        let codepoint = metadata.and_then(|m| m.codepoint);
+        let pascal_name = metadata
+            .and_then(|m| m.pascal_name.clone())
+            .unwrap_or_else(|| icon_name.to_case(Case::Pascal));
+
+        let mut search = format!(
+            "{icon_name} {} {}",
+            pascal_name.to_lowercase(),
+            tags.join(" ")
+        );
+        if let Some(codepoint) = codepoint {
+            write!(search, " {codepoint} u+{codepoint:04x}").unwrap();
+        }
+
+        let mut figures = String::new();
+        for style in icon_styles {
+            let path = format!("{ASSETS_DIR}/{style}/{icon_name}.svg");
+            let svg_raw = fs::read_to_string(&path).unwrap();
+            let svg = normalize_svg(&svg_raw, precision)
+                .unwrap_or_else(|e| panic!("Error reading {path}: {e}"));
+            let (min_x, min_y, width, height) = svg.view_box;
+            write!(
+                figures,
+                r#"<figure data-style="{style}"><svg xmlns="http://www.w3.org/2000/svg" viewBox="{min_x} {min_y} {width} {height}" fill="currentColor" role="img" aria-label="{icon_name} ({style})">{}</svg><figcaption>{style}</figcaption></figure>"#,
+                svg.markup,
+                style = escape(style),
+                icon_name = escape(icon_name),
+            )
+            .unwrap();
+        }
+
+        let codepoint = match codepoint {
+            Some(codepoint) => format!("{codepoint} (U+{codepoint:04X})"),
+            None => "none".to_string(),
+        };
+        write!(
+            cards,
+            r#"
+<article data-search="{search}" data-sets="{sets}" data-styles="{styles}">
+<h2>{icon_name}</h2>
+<div class="styles">{figures}</div>
+<dl><dt>Component</dt><dd>{pascal_name}</dd><dt>Tags</dt><dd>{tags}</dd><dt>Sets</dt><dd>{set_list}</dd><dt>Codepoint</dt><dd>{codepoint}</dd></dl>
+</article>"#,
+            search = escape(&search.to_lowercase()),
+            sets = escape(&sets.join(" ")),
+            set_list = escape(&sets.join(", ")),
+            styles = escape(&icon_styles.iter().cloned().collect::<Vec<_>>().join(" ")),
+            icon_name = escape(icon_name),
+            pascal_name = escape(&pascal_name),
+            tags = escape(&tags.join(", ")),
+        )
+        .unwrap();
+    }
+
+    let page = format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Lumo icons</title>
+<style>{STYLE}</style>
+</head>
+<body>
+<header>
+<h1>Lumo icons</h1>
+<input id="search" type="search" placeholder="Search names, tags and codepoints" aria-label="Search">
+<select id="set" aria-label="Set">{set_options}</select>
+<select id="style" aria-label="Style">{style_options}</select>
+<output id="count"></output>
+</header>
+<main>{cards}
+</main>
+<script>{SCRIPT}</script>
+</body>
+</html>
+"#,
+        set_options = options("All sets", SETS.iter().copied()),
+        style_options = options("All styles", styles.iter().map(String::as_str)),
+    );
+
+    fs::create_dir_all(out_dir).unwrap();
+    let path = Path::new(out_dir).join("index.html");
+    fs::write(&path, page).unwrap();
+    println!(
+        "Wrote {} icons in {} styles to {}",
+        assets.len(),
+        styles.len(),
+        path.display()
+    );
+}
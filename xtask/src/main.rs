@@ -1,17 +1,116 @@
 use clap::Parser;
 
+mod add;
+mod font;
+mod gallery;
+mod optimize;
+mod sanitize;
+mod svg;
+mod transform;
 mod update;
+mod validate;
 
 #[derive(Parser)]
 enum Command {
     /// Re-generate the icon components from the original SVG files
-    Update,
+    Update {
+        /// How many decimals to keep in coordinates
+        #[arg(long, default_value_t = 3)]
+        precision: usize,
+    },
+    /// Compile the icons of one style into TTF and WOFF2 fonts, with a CSS file
+    /// mapping class names to the codepoints from the metadata
+    Font {
+        /// The style to build the font from, e.g. `micro-bold`
+        #[arg(long)]
+        style: String,
+        /// Where to write the fonts and the CSS file
+        #[arg(long, default_value = "target/fonts")]
+        out_dir: String,
+        /// How many decimals to keep in coordinates
+        #[arg(long, default_value_t = 3)]
+        precision: usize,
+    },
+    /// Check that the metadata agrees with the SVG files, exiting with an error
+    /// listing every problem if it doesn't
+    Validate,
+    /// Copy an SVG file into a style folder, record it in the metadata and
+    /// re-generate the icon components
+    Add {
+        /// The SVG file to import
+        file: String,
+        /// The style the file is drawn in, e.g. `micro-bold`
+        #[arg(long)]
+        style: String,
+        /// The icon's name, the file name in kebab case by default
+        #[arg(long)]
+        name: Option<String>,
+        /// The sets to publish the icon in, required for new icons
+        #[arg(long, value_delimiter = ',')]
+        sets: Vec<String>,
+        /// Categories to list the icon under
+        #[arg(long, value_delimiter = ',')]
+        categories: Vec<String>,
+        /// Search terms for the icon
+        #[arg(long, value_delimiter = ',')]
+        tags: Vec<String>,
+        /// The icon's weight, e.g. `bold`
+        #[arg(long)]
+        weight: Option<String>,
+        /// The release the icon is published or updated in, the latest one in
+        /// the metadata by default
+        #[arg(long)]
+        release: Option<f64>,
+        /// How many decimals to keep in coordinates
+        #[arg(long, default_value_t = 3)]
+        precision: usize,
+    },
+    /// Write a static HTML page showing every icon in every style it is drawn
+    /// in, with its metadata and a search
+    Gallery {
+        /// Where to write `index.html`
+        #[arg(long, default_value = "target/gallery")]
+        out_dir: String,
+        /// How many decimals to keep in coordinates
+        #[arg(long, default_value_t = 3)]
+        precision: usize,
+    },
 }
 
 impl Command {
     fn run(&self) {
         match self {
-            Self::Update => update::run(),
+            Self::Update { precision } => update::run(*precision),
+            Self::Font {
+                style,
+                out_dir,
+                precision,
+            } => font::run(style, out_dir, *precision),
+            Self::Validate => validate::run(),
+            Self::Add {
+                file,
+                style,
+                name,
+                sets,
+                categories,
+                tags,
+                weight,
+                release,
+                precision,
+            } => add::run(
+                file,
+                &add::Options {
+                    style,
+                    name: name.as_deref(),
+                    sets,
+                    categories,
+                    tags,
+                    weight: weight.as_deref(),
+                    release: *release,
+                },
+                *precision,
+            ),
+            Self::Gallery { out_dir, precision } => gallery::run(out_dir, *precision),
         }
     }
 }
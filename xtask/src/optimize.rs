@@ -0,0 +1,524 @@
+//! Shrinks normalized icons before they are embedded in the generated code,
+//! in the spirit of svgo: editor leftovers are dropped, redundant groups are
+//! merged, numbers are rounded and paths are rewritten in their shortest form.
+
+use crate::svg::{self, Element, Node, Segment};
+
+/// Elements that never render.
+pub(crate) const METADATA_ELEMENTS: [&str; 3] = ["title", "desc", "metadata"];
+
+/// Namespace prefixes that vector editors leave behind.
+const EDITOR_PREFIXES: [&str; 4] = ["inkscape:", "sodipodi:", "sketch:", "serif:"];
+
+/// Class names the exports put on every icon.
+const EDITOR_CLASSES: [&str; 1] = ["nc-icon-wrapper"];
+
+/// Attributes a group can hand down to its only child without changing how it
+/// renders. Everything but `opacity` and `transform` is inherited anyway.
+const MOVABLE_GROUP_ATTRIBUTES: [&str; 16] = [
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "stroke",
+    "stroke-width",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-opacity",
+    "clip-rule",
+    "color",
+    "visibility",
+    "opacity",
+    "transform",
+];
+
+/// Attributes holding a single number in user units.
+const NUMBER_ATTRIBUTES: [&str; 16] = [
+    "x",
+    "y",
+    "width",
+    "height",
+    "rx",
+    "ry",
+    "cx",
+    "cy",
+    "r",
+    "x1",
+    "y1",
+    "x2",
+    "y2",
+    "stroke-width",
+    "stroke-miterlimit",
+    "stroke-dashoffset",
+];
+
+/// The paint an element inherits. The `<svg>` that `Icon` renders sets `fill`
+/// to the icon color and no stroke.
+#[derive(Clone)]
+struct Inherited {
+    fill: String,
+    stroke: String,
+}
+
+/// Optimizes the children of `root`, rounding numbers to `precision` decimals.
+pub(crate) fn optimize(root: &mut Element, precision: usize) {
+    // Only the children are embedded, so they inherit from the `<svg>` of
+    // `Icon` rather than from the paint on the asset's own root.
+    let inherited = Inherited {
+        fill: "currentColor".to_string(),
+        stroke: "none".to_string(),
+    };
+    strip_children(root);
+    for child in root.elements_mut() {
+        clean(child, &inherited);
+    }
+    collapse_groups(root);
+    round_numbers(root, precision);
+}
+
+/// Removes the metadata and editor elements among the children of `element`,
+/// and the whitespace between them outside of text.
+pub(crate) fn strip_children(element: &mut Element) {
+    let keeps_whitespace = matches!(element.name.as_str(), "text" | "tspan" | "textPath");
+    element.children.retain(|child| match child {
+        Node::Element(e) => {
+            !METADATA_ELEMENTS.contains(&e.name.as_str()) && !is_editor_name(&e.name)
+        }
+        Node::Text(text) => keeps_whitespace || !text.trim().is_empty(),
+    });
+}
+
+fn clean(element: &mut Element, inherited: &Inherited) {
+    strip_children(element);
+
+    element
+        .attributes
+        .retain(|(name, _)| name != "data-color" && !is_editor_name(name));
+    if let Some(class) = element.remove_attribute("class") {
+        let class: Vec<_> = class
+            .split_whitespace()
+            .filter(|c| !EDITOR_CLASSES.contains(c))
+            .collect();
+        if !class.is_empty() {
+            element
+                .attributes
+                .push(("class".to_string(), class.join(" ")));
+        }
+    }
+
+    // A shape with a zero stroke width has no stroke at all.
+    let is_shape = !element
+        .children
+        .iter()
+        .any(|child| matches!(child, Node::Element(_)));
+    let zero_width = element
+        .attribute("stroke-width")
+        .and_then(|w| w.parse::<f64>().ok())
+        == Some(0.0);
+    if is_shape && zero_width {
+        element
+            .attributes
+            .retain(|(name, _)| !name.starts_with("stroke"));
+        if inherited.stroke != "none" {
+            element.set_attribute("stroke", "none".to_string());
+        }
+    }
+
+    // Paint that repeats what is inherited changes nothing.
+    for (name, value) in [("fill", &inherited.fill), ("stroke", &inherited.stroke)] {
+        if element
+            .attribute(name)
+            .is_some_and(|own| own.eq_ignore_ascii_case(value))
+        {
+            element.remove_attribute(name);
+        }
+    }
+
+    let inherited = Inherited {
+        fill: element.property("fill").unwrap_or(inherited.fill.clone()),
+        stroke: element
+            .property("stroke")
+            .unwrap_or(inherited.stroke.clone()),
+    };
+    for child in element.elements_mut() {
+        clean(child, &inherited);
+    }
+}
+
+pub(crate) fn is_editor_name(name: &str) -> bool {
+    EDITOR_PREFIXES
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+}
+
+/// Removes empty groups, unwraps groups without attributes, and folds groups
+/// with a single child into that child.
+fn collapse_groups(element: &mut Element) {
+    element.elements_mut().for_each(collapse_groups);
+
+    let mut children = Vec::with_capacity(element.children.len());
+    for child in element.children.drain(..) {
+        match child {
+            Node::Element(g) if g.name == "g" && g.children.is_empty() => {}
+            Node::Element(g) if g.name == "g" && g.attributes.is_empty() => {
+                children.extend(g.children);
+            }
+            Node::Element(mut g) if g.name == "g" && can_fold(&g) => {
+                let Some(Node::Element(mut only)) = g.children.pop() else {
+                    unreachable!()
+                };
+                for (name, value) in g.attributes {
+                    match (name.as_str(), only.remove_attribute(&name)) {
+                        ("transform", Some(own)) => {
+                            only.set_attribute(&name, format!("{value} {own}"))
+                        }
+                        (_, Some(own)) => only.set_attribute(&name, own),
+                        (_, None) => only.attributes.push((name, value)),
+                    }
+                }
+                children.push(Node::Element(only));
+            }
+            child => children.push(child),
+        }
+    }
+    element.children = children;
+}
+
+fn can_fold(g: &Element) -> bool {
+    let [Node::Element(only)] = g.children.as_slice() else {
+        return false;
+    };
+    g.attributes.iter().all(|(name, _)| {
+        MOVABLE_GROUP_ATTRIBUTES.contains(&name.as_str())
+            // Opacities multiply, so they can only move if the child has none.
+            && (name != "opacity" || only.attribute("opacity").is_none())
+    })
+}
+
+fn round_numbers(element: &mut Element, precision: usize) {
+    for (name, value) in &mut element.attributes {
+        if NUMBER_ATTRIBUTES.contains(&name.as_str()) {
+            if let Ok(number) = value.parse::<f64>() {
+                *value = format_number(round(number, precision));
+            }
+        } else if name == "d" {
+            if let Some(path) = shorten_path(value, precision) {
+                *value = path;
+            }
+        } else if name == "stroke-dasharray" {
+            let dashes: Option<Vec<_>> = value
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|n| !n.is_empty())
+                .map(|n| n.parse().ok().map(|n| format_number(round(n, precision))))
+                .collect();
+            if let Some(dashes) = dashes {
+                *value = dashes.join(" ");
+            }
+        } else if name == "points" {
+            let mut points = Numbers::default();
+            for (x, y) in svgtypes::PointsParser::from(value.as_str()) {
+                points.number(round(x, precision));
+                points.number(round(y, precision));
+            }
+            *value = points.out;
+        }
+    }
+    for child in element.elements_mut() {
+        round_numbers(child, precision);
+    }
+}
+
+fn round(value: f64, precision: usize) -> f64 {
+    let factor = 10f64.powi(precision as i32);
+    let rounded = (value * factor).round() / factor;
+    // Avoid printing "-0".
+    if rounded == 0.0 {
+        0.0
+    } else {
+        rounded
+    }
+}
+
+/// Formats a number without a leading zero, e.g. `.5` and `-.25`.
+fn format_number(value: f64) -> String {
+    let s = value.to_string();
+    if let Some(rest) = s.strip_prefix("0.") {
+        format!(".{rest}")
+    } else if let Some(rest) = s.strip_prefix("-0.") {
+        format!("-.{rest}")
+    } else {
+        s
+    }
+}
+
+/// Path data being written with as few separators as possible.
+#[derive(Clone, Default)]
+struct Numbers {
+    out: String,
+    /// The command an implicit repeat stands for.
+    command: Option<char>,
+    after_number: bool,
+    after_decimal: bool,
+}
+
+impl Numbers {
+    fn command(&mut self, command: char) {
+        // A repeated command can be left out, and so can a line after a move.
+        if self.command != Some(command) || !self.after_number {
+            self.out.push(command);
+            self.after_number = false;
+        }
+        self.command = match command {
+            'M' => Some('L'),
+            'm' => Some('l'),
+            'Z' | 'z' => None,
+            c => Some(c),
+        };
+    }
+
+    fn number(&mut self, value: f64) {
+        let s = format_number(value);
+        let glued = s.starts_with('-') || (s.starts_with('.') && self.after_decimal);
+        if self.after_number && !glued {
+            self.out.push(' ');
+        }
+        self.after_decimal = s.contains('.');
+        self.out.push_str(&s);
+        self.after_number = true;
+    }
+
+    fn flag(&mut self, flag: bool) {
+        self.number(if flag { 1.0 } else { 0.0 });
+    }
+}
+
+/// Rewrites path data with rounded numbers, choosing between absolute and
+/// relative commands, lines and their horizontal or vertical shorthands,
+/// whichever is shorter. Returns `None` if the data doesn't parse.
+fn shorten_path(d: &str, precision: usize) -> Option<String> {
+    let r = |v: f64| round(v, precision);
+
+    let segments = svg::parse_path(d)?
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Arc {
+                radii,
+                large_arc,
+                sweep,
+                to,
+            } => Segment::Arc {
+                radii: radii.map(r),
+                large_arc,
+                sweep,
+                to: (r(to.0), r(to.1)),
+            },
+            segment => segment.map_points(|x, y| (r(x), r(y))),
+        });
+
+    // The current point as written, so relative numbers don't drift.
+    let (mut cx, mut cy, mut sx, mut sy) = (0.0, 0.0, 0.0, 0.0);
+    let mut out = Numbers::default();
+    for segment in segments {
+        let candidates: Vec<(char, Vec<f64>)> = match &segment {
+            Segment::MoveTo(x, y) => vec![('M', vec![*x, *y]), ('m', vec![r(x - cx), r(y - cy)])],
+            Segment::LineTo(x, y) if *y == cy => vec![('H', vec![*x]), ('h', vec![r(x - cx)])],
+            Segment::LineTo(x, y) if *x == cx => vec![('V', vec![*y]), ('v', vec![r(y - cy)])],
+            Segment::LineTo(x, y) => vec![('L', vec![*x, *y]), ('l', vec![r(x - cx), r(y - cy)])],
+            Segment::CurveTo(p) => vec![('C', p.to_vec()), ('c', relative(p, cx, cy, r))],
+            Segment::SmoothCurveTo(p) => {
+                vec![('S', p.to_vec()), ('s', relative(p, cx, cy, r))]
+            }
+            Segment::Quadratic(p) => vec![('Q', p.to_vec()), ('q', relative(p, cx, cy, r))],
+            Segment::SmoothQuadratic(x, y) => {
+                vec![('T', vec![*x, *y]), ('t', vec![r(x - cx), r(y - cy)])]
+            }
+            Segment::Arc { to: (x, y), .. } => {
+                vec![('A', vec![*x, *y]), ('a', vec![r(x - cx), r(y - cy)])]
+            }
+            Segment::ClosePath => vec![('z', vec![])],
+        };
+
+        let write = |out: &mut Numbers, (command, numbers): &(char, Vec<f64>)| {
+            out.command(*command);
+            if let Segment::Arc {
+                radii,
+                large_arc,
+                sweep,
+                ..
+            } = &segment
+            {
+                radii.iter().for_each(|&n| out.number(n));
+                out.flag(*large_arc);
+                out.flag(*sweep);
+            }
+            numbers.iter().for_each(|&n| out.number(n));
+        };
+        let shortest = candidates
+            .iter()
+            .map(|candidate| {
+                let mut attempt = Numbers {
+                    out: String::new(),
+                    ..out.clone()
+                };
+                write(&mut attempt, candidate);
+                (attempt.out.len(), candidate)
+            })
+            .min_by_key(|(len, _)| *len)
+            .map(|(_, candidate)| candidate)
+            .unwrap();
+        write(&mut out, shortest);
+
+        match segment {
+            Segment::MoveTo(x, y) => {
+                (cx, cy) = (x, y);
+                (sx, sy) = (x, y);
+            }
+            Segment::LineTo(x, y) | Segment::SmoothQuadratic(x, y) => (cx, cy) = (x, y),
+            Segment::CurveTo([.., x, y])
+            | Segment::SmoothCurveTo([.., x, y])
+            | Segment::Quadratic([.., x, y]) => (cx, cy) = (x, y),
+            Segment::Arc { to, .. } => (cx, cy) = to,
+            Segment::ClosePath => (cx, cy) = (sx, sy),
+        }
+    }
+
+    Some(out.out)
+}
+
+/// Points relative to the current point.
+fn relative(points: &[f64], cx: f64, cy: f64, r: impl Fn(f64) -> f64) -> Vec<f64> {
+    points
+        .chunks(2)
+        .flat_map(|p| [r(p[0] - cx), r(p[1] - cy)])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The command letter of each segment and its numbers, flags included.
+    fn flatten(d: &str) -> Vec<(char, Vec<f64>)> {
+        svg::parse_path(d)
+            .unwrap_or_else(|| panic!("`{d}` doesn't parse"))
+            .into_iter()
+            .map(|segment| match segment {
+                Segment::MoveTo(x, y) => ('M', vec![x, y]),
+                Segment::LineTo(x, y) => ('L', vec![x, y]),
+                Segment::CurveTo(p) => ('C', p.to_vec()),
+                Segment::SmoothCurveTo(p) => ('S', p.to_vec()),
+                Segment::Quadratic(p) => ('Q', p.to_vec()),
+                Segment::SmoothQuadratic(x, y) => ('T', vec![x, y]),
+                Segment::Arc {
+                    radii,
+                    large_arc,
+                    sweep,
+                    to,
+                } => {
+                    let flag = |flag| if flag { 1.0 } else { 0.0 };
+                    let mut numbers = radii.to_vec();
+                    numbers.extend([flag(large_arc), flag(sweep), to.0, to.1]);
+                    ('A', numbers)
+                }
+                Segment::ClosePath => ('Z', vec![]),
+            })
+            .collect()
+    }
+
+    fn optimized(svg: &str) -> String {
+        let mut root = svg::parse(svg).unwrap();
+        optimize(&mut root, 3);
+        svg::to_markup(&root.children)
+    }
+
+    #[test]
+    fn keeps_paint_that_only_repeats_the_root_of_the_asset() {
+        assert_eq!(
+            optimized(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" fill="none" stroke="currentColor"><title>Ring</title><circle cx="5" cy="5" r="4" fill="none" stroke="currentColor"/></svg>"#
+            ),
+            r#"<circle cx="5" cy="5" r="4" fill="none" stroke="currentColor"></circle>"#
+        );
+    }
+
+    #[test]
+    fn drops_paint_that_repeats_what_is_inherited() {
+        assert_eq!(
+            optimized(
+                r#"<svg xmlns="http://www.w3.org/2000/svg"><g fill="none"><path d="M0 0H1" fill="none"/><path d="M0 0V1" fill="currentColor"/></g><path d="M1 1H2" fill="currentColor" stroke="none"/></svg>"#
+            ),
+            r#"<g fill="none"><path d="M0 0H1"></path><path d="M0 0V1" fill="currentColor"></path></g><path d="M1 1H2"></path>"#
+        );
+    }
+
+    /// Shortens `d` and checks that it still draws the same points.
+    fn shorten(d: &str, precision: usize) -> String {
+        let shortened = shorten_path(d, precision).unwrap();
+        let (expected, actual) = (flatten(d), flatten(&shortened));
+        assert_eq!(expected.len(), actual.len(), "`{d}` became `{shortened}`");
+        let tolerance = 10f64.powi(-(precision as i32));
+        for ((command, numbers), (actual_command, actual_numbers)) in expected.iter().zip(&actual) {
+            assert_eq!(command, actual_command, "`{d}` became `{shortened}`");
+            assert_eq!(numbers.len(), actual_numbers.len());
+            for (n, actual) in numbers.iter().zip(actual_numbers) {
+                assert!(
+                    (n - actual).abs() <= tolerance,
+                    "`{d}` became `{shortened}`: {n} != {actual}"
+                );
+            }
+        }
+        shortened
+    }
+
+    #[test]
+    fn glues_negative_numbers_and_decimals() {
+        assert_eq!(shorten("M 0 0 L -1 -2", 3), "M0 0-1-2");
+        assert_eq!(shorten("M 0 0 L 0.5 0.5", 3), "M0 0 .5.5");
+        assert_eq!(shorten("M 0.5 1 L 0.5 0.25", 3), "M.5 1V.25");
+    }
+
+    #[test]
+    fn leaves_out_repeated_commands() {
+        assert_eq!(
+            shorten("M0 0 C1 1 2 2 3 3 C4 4 5 5 6 6", 3),
+            "M0 0C1 1 2 2 3 3 4 4 5 5 6 6"
+        );
+        assert_eq!(shorten("M0 0 L1 2 L3 5", 3), "M0 0 1 2 3 5");
+    }
+
+    #[test]
+    fn uses_shorthands_for_horizontal_and_vertical_lines() {
+        assert_eq!(shorten("M1 1 L5 1 L5 4 Z", 3), "M1 1H5V4z");
+    }
+
+    #[test]
+    fn picks_relative_commands_when_they_are_shorter() {
+        assert_eq!(
+            shorten("M100.5 100.5 L101 101.25 L101.75 101.25", 3),
+            "M100.5 100.5l.5.75h.75"
+        );
+    }
+
+    #[test]
+    fn writes_arc_flags() {
+        assert_eq!(
+            shorten("M10 10 A 5 5 0 0 1 20 10 A 5 5 0 1 0 10 10", 3),
+            "M10 10a5 5 0 0 1 10 0 5 5 0 1 0-10 0"
+        );
+    }
+
+    #[test]
+    fn rounds_to_the_precision() {
+        assert_eq!(
+            shorten("M0.12345 3.14159 L10.0001 -0.0004", 2),
+            "M.12 3.14 10 0"
+        );
+        shorten(
+            "M12.3456 7.891 c1.2345 0 2.3456 1.0001 2.3456 2.2222 s-1.111 2.2 -2.2 2.2 \
+             q-3.333 0 -3.333-3.333 t1.5 -1.5 a1.25 1.25 0 1 1 2.5 0 z m5 5 h-0.3333 v0.6667",
+            2,
+        );
+    }
+}
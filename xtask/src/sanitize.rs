@@ -0,0 +1,328 @@
+//! Checks assets against an allow-list of SVG elements and attributes before
+//! their markup is embedded in the generated code, which `Icon` injects into
+//! the page as is. Anything that could run script, load another resource or
+//! embed HTML is rejected rather than removed, so the asset gets fixed at the
+//! source.
+
+use crate::optimize::{is_editor_name, METADATA_ELEMENTS};
+use crate::svg::{self, Element, Node};
+
+/// Elements that only draw, or define paint and geometry for other elements.
+/// The generator also hands this list to the Leptos `Icon`, which builds them
+/// one by one under the `trusted-types` feature.
+pub(crate) const ELEMENTS: &[&str] = &[
+    "svg",
+    "g",
+    "defs",
+    "symbol",
+    "use",
+    "path",
+    "rect",
+    "circle",
+    "ellipse",
+    "line",
+    "polyline",
+    "polygon",
+    "text",
+    "tspan",
+    "textPath",
+    "linearGradient",
+    "radialGradient",
+    "stop",
+    "clipPath",
+    "mask",
+    "pattern",
+    "marker",
+    "filter",
+    "feBlend",
+    "feColorMatrix",
+    "feComposite",
+    "feDropShadow",
+    "feFlood",
+    "feGaussianBlur",
+    "feMerge",
+    "feMergeNode",
+    "feMorphology",
+    "feOffset",
+];
+
+/// Geometry, paint and structural attributes, which are also the properties a
+/// `style` attribute may set.
+const ATTRIBUTES: &[&str] = &[
+    // Structure
+    "id",
+    "class",
+    "style",
+    "version",
+    "baseProfile",
+    "xml:space",
+    "xml:lang",
+    "href",
+    "xlink:href",
+    "viewBox",
+    "preserveAspectRatio",
+    "enable-background",
+    // Geometry
+    "x",
+    "y",
+    "x1",
+    "y1",
+    "x2",
+    "y2",
+    "cx",
+    "cy",
+    "r",
+    "rx",
+    "ry",
+    "fx",
+    "fy",
+    "fr",
+    "dx",
+    "dy",
+    "width",
+    "height",
+    "d",
+    "points",
+    "pathLength",
+    "transform",
+    "transform-origin",
+    // Paint
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "stroke",
+    "stroke-width",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-opacity",
+    "opacity",
+    "color",
+    "clip-path",
+    "clip-rule",
+    "mask",
+    "filter",
+    "marker-start",
+    "marker-mid",
+    "marker-end",
+    "display",
+    "visibility",
+    "overflow",
+    "isolation",
+    "mix-blend-mode",
+    "paint-order",
+    "vector-effect",
+    "shape-rendering",
+    "color-interpolation",
+    "color-interpolation-filters",
+    // Gradients, patterns, clips, masks and markers
+    "offset",
+    "stop-color",
+    "stop-opacity",
+    "gradientUnits",
+    "gradientTransform",
+    "spreadMethod",
+    "patternUnits",
+    "patternContentUnits",
+    "patternTransform",
+    "clipPathUnits",
+    "maskUnits",
+    "maskContentUnits",
+    "markerUnits",
+    "markerWidth",
+    "markerHeight",
+    "refX",
+    "refY",
+    "orient",
+    // Filters
+    "filterUnits",
+    "primitiveUnits",
+    "in",
+    "in2",
+    "result",
+    "mode",
+    "type",
+    "values",
+    "operator",
+    "k1",
+    "k2",
+    "k3",
+    "k4",
+    "radius",
+    "stdDeviation",
+    "edgeMode",
+    "flood-color",
+    "flood-opacity",
+    // Text
+    "font-family",
+    "font-size",
+    "font-style",
+    "font-weight",
+    "letter-spacing",
+    "word-spacing",
+    "text-anchor",
+    "dominant-baseline",
+    "startOffset",
+];
+
+/// Lists everything in an asset that isn't on the allow-list: disallowed
+/// elements and attributes, and references to anything outside the file.
+pub(crate) fn check(root: &Element) -> Vec<String> {
+    let mut problems = Vec::new();
+    check_element(root, &mut problems);
+    problems
+}
+
+fn check_element(element: &Element, problems: &mut Vec<String>) {
+    let tag = &element.name;
+    if !ELEMENTS.contains(&tag.as_str()) {
+        problems.push(format!("<{tag}> isn't an allowed element"));
+        return;
+    }
+
+    for (name, value) in &element.attributes {
+        // Editor attributes are dropped by the optimizer, and `data-*` attributes
+        // (like the `data-color` of duotone layers) are inert.
+        if is_editor_name(name) || name.starts_with("data-") {
+            continue;
+        }
+        if !ATTRIBUTES.contains(&name.as_str()) {
+            problems.push(format!("`{name}` on <{tag}> isn't an allowed attribute"));
+            continue;
+        }
+
+        if name == "href" || name == "xlink:href" {
+            if !value.trim_start().starts_with('#') {
+                problems.push(format!(
+                    "`{name}=\"{value}\"` on <{tag}> points outside the file"
+                ));
+            }
+        } else if name == "style" {
+            for (property, value) in svg::declarations(value) {
+                if !ATTRIBUTES.contains(&property.as_str()) {
+                    problems.push(format!(
+                        "`{property}` in the style of <{tag}> isn't an allowed property"
+                    ));
+                } else {
+                    check_urls(tag, &property, &value, problems);
+                }
+            }
+        } else {
+            check_urls(tag, name, value, problems);
+        }
+    }
+
+    for child in &element.children {
+        if let Node::Element(child) = child {
+            // Metadata and editor elements are dropped by the optimizer, along
+            // with whatever the editors put inside them.
+            if METADATA_ELEMENTS.contains(&child.name.as_str()) || is_editor_name(&child.name) {
+                continue;
+            }
+            check_element(child, problems);
+        }
+    }
+}
+
+/// Checks that the `url()`s in a value only refer to elements in the same file,
+/// like the gradient in `fill="url(#gradient)"`.
+fn check_urls(tag: &str, name: &str, value: &str, problems: &mut Vec<String>) {
+    let lowercase = value.to_ascii_lowercase();
+    let mut rest = lowercase.as_str();
+    while let Some(start) = rest.find("url(") {
+        rest = &rest[start + "url(".len()..];
+        let end = rest.find(')').unwrap_or(rest.len());
+        let target = rest[..end].trim().trim_matches(|c| c == '"' || c == '\'');
+        if !target.starts_with('#') {
+            problems.push(format!(
+                "`{name}: {value}` on <{tag}> points outside the file"
+            ));
+            return;
+        }
+        rest = &rest[end..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The problems with the given children of an otherwise clean document.
+    fn problems(children: &str) -> Vec<String> {
+        let root = svg::parse(&format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" xmlns:inkscape="http://www.inkscape.org/namespaces/inkscape" xmlns:sodipodi="http://sodipodi.sourceforge.net/DTD/sodipodi-0.dtd" viewBox="0 0 20 20">{children}</svg>"#
+        ))
+        .unwrap();
+        check(&root)
+    }
+
+    #[test]
+    fn rejects_elements_that_run_script_or_embed_content() {
+        assert_eq!(
+            problems("<script>alert(1)</script>"),
+            ["<script> isn't an allowed element"]
+        );
+        assert_eq!(
+            problems("<foreignObject><div/></foreignObject>"),
+            ["<foreignObject> isn't an allowed element"]
+        );
+        assert_eq!(
+            problems(r##"<image href="#a"/>"##),
+            ["<image> isn't an allowed element"]
+        );
+    }
+
+    #[test]
+    fn rejects_event_handlers() {
+        assert_eq!(
+            problems(r#"<path d="M0 0h1" onload="alert(1)"/>"#),
+            ["`onload` on <path> isn't an allowed attribute"]
+        );
+    }
+
+    #[test]
+    fn rejects_links_outside_the_file() {
+        assert_eq!(
+            problems(r#"<use href="https://example.com/icons.svg#a"/>"#),
+            ["`href=\"https://example.com/icons.svg#a\"` on <use> points outside the file"]
+        );
+        assert_eq!(
+            problems(r#"<use xlink:href="javascript:alert(1)"/>"#),
+            ["`xlink:href=\"javascript:alert(1)\"` on <use> points outside the file"]
+        );
+    }
+
+    #[test]
+    fn rejects_urls_outside_the_file() {
+        assert_eq!(
+            problems(r#"<path d="M0 0h1" fill="url(https://example.com/a.svg#g)"/>"#),
+            ["`fill: url(https://example.com/a.svg#g)` on <path> points outside the file"]
+        );
+        assert_eq!(
+            problems(r#"<path d="M0 0h1" style="fill:url(//example.com/a.svg#g)"/>"#),
+            ["`fill: url(//example.com/a.svg#g)` on <path> points outside the file"]
+        );
+    }
+
+    #[test]
+    fn allows_references_in_the_file_and_data_attributes() {
+        assert_eq!(
+            problems(
+                r##"<defs><linearGradient id="g"><stop offset="0" stop-color="red"/></linearGradient></defs><path d="M0 0h1" fill="url(#g)" style="stroke: url('#g')" data-color="color-2"/><use href="#g"/>"##
+            ),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn skips_what_the_optimizer_drops() {
+        assert_eq!(
+            problems(
+                r#"<metadata><script>alert(1)</script></metadata><title>Bell</title><sodipodi:namedview><inkscape:grid><image/></inkscape:grid></sodipodi:namedview><path d="M0 0h1" inkscape:label="Bell"/>"#
+            ),
+            Vec::<String>::new()
+        );
+    }
+}
@@ -0,0 +1,521 @@
+//! A small owned SVG tree, so the update pipeline can transform assets
+//! structurally instead of matching on their text.
+
+use std::fmt;
+
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+const XLINK_NAMESPACE: &str = "http://www.w3.org/1999/xlink";
+
+#[derive(Debug, Clone)]
+pub(crate) enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Element {
+    pub name: String,
+    /// The attributes in source order, with prefixed names such as `xlink:href`.
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+#[derive(Debug)]
+pub(crate) enum Error {
+    Xml(roxmltree::Error),
+    NotSvg(String),
+    ViewBox(String),
+    MissingViewBox,
+    Transform(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Xml(e) => write!(f, "malformed XML: {e}"),
+            Error::NotSvg(name) => write!(f, "the root element is <{name}>, expected <svg>"),
+            Error::ViewBox(value) => write!(f, "invalid viewBox \"{value}\""),
+            Error::MissingViewBox => write!(f, "no viewBox, width or height"),
+            Error::Transform(value) => write!(f, "invalid transform \"{value}\""),
+        }
+    }
+}
+
+/// Parses an SVG document into its root `<svg>` element.
+///
+/// Comments and processing instructions are dropped, entities are resolved.
+pub(crate) fn parse(text: &str) -> Result<Element, Error> {
+    let doc = roxmltree::Document::parse(text).map_err(Error::Xml)?;
+    let root = doc.root_element();
+    if root.tag_name().name() != "svg" {
+        return Err(Error::NotSvg(root.tag_name().name().to_string()));
+    }
+    Ok(element(root))
+}
+
+fn element(node: roxmltree::Node) -> Element {
+    let qualified = |namespace: Option<&str>, name: &str| {
+        let prefix = match namespace {
+            Some(XML_NAMESPACE) => Some("xml"),
+            Some(uri) => node.lookup_prefix(uri).filter(|p| !p.is_empty()),
+            None => None,
+        };
+        match prefix {
+            Some(prefix) => format!("{prefix}:{name}"),
+            None => name.to_string(),
+        }
+    };
+
+    Element {
+        name: qualified(node.tag_name().namespace(), node.tag_name().name()),
+        attributes: node
+            .attributes()
+            .map(|a| (qualified(a.namespace(), a.name()), a.value().to_string()))
+            .collect(),
+        children: node
+            .children()
+            .filter_map(|child| {
+                if child.is_element() {
+                    Some(Node::Element(element(child)))
+                } else if child.is_text() {
+                    child.text().map(|text| Node::Text(text.to_string()))
+                } else {
+                    None
+                }
+            })
+            .collect(),
+    }
+}
+
+/// A `viewBox` as `(min_x, min_y, width, height)`.
+pub(crate) type ViewBox = (f32, f32, f32, f32);
+
+impl Element {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Replaces the value of an attribute in place, or appends it.
+    pub fn set_attribute(&mut self, name: &str, value: String) {
+        match self.attributes.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value,
+            None => self.attributes.push((name.to_string(), value)),
+        }
+    }
+
+    pub fn remove_attribute(&mut self, name: &str) -> Option<String> {
+        let index = self.attributes.iter().position(|(n, _)| n == name)?;
+        Some(self.attributes.remove(index).1)
+    }
+
+    /// The value of a presentation property, from `style` if it is declared
+    /// there and from the attribute otherwise.
+    pub fn property(&self, name: &str) -> Option<String> {
+        self.attribute("style")
+            .and_then(|style| {
+                declarations(style)
+                    .into_iter()
+                    .rev()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, v)| v)
+            })
+            .or_else(|| self.attribute(name).map(str::to_string))
+    }
+
+    /// Sets a presentation property where it is declared: in `style` if it is
+    /// there, and as an attribute otherwise.
+    pub fn set_property(&mut self, name: &str, value: String) {
+        if let Some(style) = self.attribute("style") {
+            let mut declarations = declarations(style);
+            if let Some(declared) = declarations.iter_mut().rev().find(|(n, _)| n == name) {
+                declared.1 = value;
+                self.set_attribute("style", to_style(&declarations));
+                return;
+            }
+        }
+        self.set_attribute(name, value);
+    }
+
+    /// The child elements, skipping text.
+    pub fn elements_mut(&mut self) -> impl Iterator<Item = &mut Element> {
+        self.children.iter_mut().filter_map(|child| match child {
+            Node::Element(e) => Some(e),
+            Node::Text(_) => None,
+        })
+    }
+
+    /// The `viewBox` of the element, falling back to its `width` and `height`.
+    pub fn view_box(&self) -> Result<Option<ViewBox>, Error> {
+        if let Some(value) = self.attribute("viewBox") {
+            let vb: svgtypes::ViewBox = value
+                .parse()
+                .map_err(|_| Error::ViewBox(value.to_string()))?;
+            return Ok(Some((vb.x as f32, vb.y as f32, vb.w as f32, vb.h as f32)));
+        }
+        let length = |name| {
+            self.attribute(name)
+                .and_then(|v| v.parse::<svgtypes::Length>().ok())
+                .filter(|l| l.unit == svgtypes::LengthUnit::None && l.number > 0.0)
+                .map(|l| l.number as f32)
+        };
+        Ok(length("width")
+            .zip(length("height"))
+            .map(|(w, h)| (0.0, 0.0, w, h)))
+    }
+}
+
+/// Splits a `style` attribute into its declarations, keeping `!important`
+/// as part of the value.
+pub(crate) fn declarations(style: &str) -> Vec<(String, String)> {
+    simplecss::DeclarationTokenizer::from(style)
+        .map(|d| {
+            let value = if d.important {
+                format!("{} !important", d.value)
+            } else {
+                d.value.to_string()
+            };
+            (d.name.to_string(), value)
+        })
+        .collect()
+}
+
+pub(crate) fn to_style(declarations: &[(String, String)]) -> String {
+    declarations
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// A path segment in absolute coordinates, with horizontal and vertical lines
+/// as plain lines.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Segment {
+    MoveTo(f64, f64),
+    LineTo(f64, f64),
+    CurveTo([f64; 6]),
+    SmoothCurveTo([f64; 4]),
+    Quadratic([f64; 4]),
+    SmoothQuadratic(f64, f64),
+    Arc {
+        /// `rx`, `ry` and the x-axis rotation in degrees.
+        radii: [f64; 3],
+        large_arc: bool,
+        sweep: bool,
+        to: (f64, f64),
+    },
+    ClosePath,
+}
+
+impl Segment {
+    /// Applies `f` to every point of the segment. Arc radii are left alone.
+    pub fn map_points(self, f: impl Fn(f64, f64) -> (f64, f64)) -> Self {
+        let pairs = |points: &mut [f64]| {
+            for point in points.chunks_mut(2) {
+                (point[0], point[1]) = f(point[0], point[1]);
+            }
+        };
+        match self {
+            Segment::MoveTo(x, y) => {
+                let (x, y) = f(x, y);
+                Segment::MoveTo(x, y)
+            }
+            Segment::LineTo(x, y) => {
+                let (x, y) = f(x, y);
+                Segment::LineTo(x, y)
+            }
+            Segment::CurveTo(mut p) => {
+                pairs(&mut p);
+                Segment::CurveTo(p)
+            }
+            Segment::SmoothCurveTo(mut p) => {
+                pairs(&mut p);
+                Segment::SmoothCurveTo(p)
+            }
+            Segment::Quadratic(mut p) => {
+                pairs(&mut p);
+                Segment::Quadratic(p)
+            }
+            Segment::SmoothQuadratic(x, y) => {
+                let (x, y) = f(x, y);
+                Segment::SmoothQuadratic(x, y)
+            }
+            Segment::Arc {
+                radii,
+                large_arc,
+                sweep,
+                to,
+            } => Segment::Arc {
+                radii,
+                large_arc,
+                sweep,
+                to: f(to.0, to.1),
+            },
+            Segment::ClosePath => Segment::ClosePath,
+        }
+    }
+}
+
+/// Parses path data into absolute segments. Returns `None` if it doesn't parse.
+pub(crate) fn parse_path(d: &str) -> Option<Vec<Segment>> {
+    use svgtypes::PathSegment as P;
+
+    // The current point and subpath start, to resolve relative segments.
+    let (mut x, mut y, mut start_x, mut start_y) = (0.0, 0.0, 0.0, 0.0);
+    let mut segments = Vec::new();
+    for segment in svgtypes::PathParser::from(d) {
+        let segment = segment.ok()?;
+        let absolute = match segment {
+            P::MoveTo { abs, .. }
+            | P::LineTo { abs, .. }
+            | P::HorizontalLineTo { abs, .. }
+            | P::VerticalLineTo { abs, .. }
+            | P::CurveTo { abs, .. }
+            | P::SmoothCurveTo { abs, .. }
+            | P::Quadratic { abs, .. }
+            | P::SmoothQuadratic { abs, .. }
+            | P::EllipticalArc { abs, .. }
+            | P::ClosePath { abs } => abs,
+        };
+        let (ox, oy) = if absolute { (0.0, 0.0) } else { (x, y) };
+        let parsed = match segment {
+            P::MoveTo { x: px, y: py, .. } => {
+                (x, y) = (ox + px, oy + py);
+                (start_x, start_y) = (x, y);
+                Segment::MoveTo(x, y)
+            }
+            P::LineTo { x: px, y: py, .. } => {
+                (x, y) = (ox + px, oy + py);
+                Segment::LineTo(x, y)
+            }
+            P::HorizontalLineTo { x: px, .. } => {
+                x = ox + px;
+                Segment::LineTo(x, y)
+            }
+            P::VerticalLineTo { y: py, .. } => {
+                y = oy + py;
+                Segment::LineTo(x, y)
+            }
+            P::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x: px,
+                y: py,
+                ..
+            } => {
+                (x, y) = (ox + px, oy + py);
+                Segment::CurveTo([ox + x1, oy + y1, ox + x2, oy + y2, x, y])
+            }
+            P::SmoothCurveTo {
+                x2,
+                y2,
+                x: px,
+                y: py,
+                ..
+            } => {
+                (x, y) = (ox + px, oy + py);
+                Segment::SmoothCurveTo([ox + x2, oy + y2, x, y])
+            }
+            P::Quadratic {
+                x1,
+                y1,
+                x: px,
+                y: py,
+                ..
+            } => {
+                (x, y) = (ox + px, oy + py);
+                Segment::Quadratic([ox + x1, oy + y1, x, y])
+            }
+            P::SmoothQuadratic { x: px, y: py, .. } => {
+                (x, y) = (ox + px, oy + py);
+                Segment::SmoothQuadratic(x, y)
+            }
+            P::EllipticalArc {
+                rx,
+                ry,
+                x_axis_rotation,
+                large_arc,
+                sweep,
+                x: px,
+                y: py,
+                ..
+            } => {
+                (x, y) = (ox + px, oy + py);
+                Segment::Arc {
+                    radii: [rx, ry, x_axis_rotation],
+                    large_arc,
+                    sweep,
+                    to: (x, y),
+                }
+            }
+            P::ClosePath { .. } => {
+                (x, y) = (start_x, start_y);
+                Segment::ClosePath
+            }
+        };
+        segments.push(parsed);
+    }
+    Some(segments)
+}
+
+/// Writes segments as path data with absolute commands.
+pub(crate) fn path_data(segments: &[Segment]) -> String {
+    let mut d = Vec::new();
+    for segment in segments {
+        let (command, numbers) = match *segment {
+            Segment::MoveTo(x, y) => ('M', vec![x, y]),
+            Segment::LineTo(x, y) => ('L', vec![x, y]),
+            Segment::CurveTo(p) => ('C', p.to_vec()),
+            Segment::SmoothCurveTo(p) => ('S', p.to_vec()),
+            Segment::Quadratic(p) => ('Q', p.to_vec()),
+            Segment::SmoothQuadratic(x, y) => ('T', vec![x, y]),
+            Segment::Arc {
+                radii: [rx, ry, rotation],
+                large_arc,
+                sweep,
+                to: (x, y),
+            } => {
+                let flag = |f: bool| if f { 1.0 } else { 0.0 };
+                (
+                    'A',
+                    vec![rx, ry, rotation, flag(large_arc), flag(sweep), x, y],
+                )
+            }
+            Segment::ClosePath => ('Z', vec![]),
+        };
+        d.push(command.to_string());
+        d.extend(numbers.iter().map(f64::to_string));
+    }
+    d.join(" ")
+}
+
+/// Serializes a root `<svg>` element into a standalone document, declaring the
+/// namespaces it needs.
+pub(crate) fn to_document(root: &Element) -> String {
+    fn uses_xlink(element: &Element) -> bool {
+        element
+            .attributes
+            .iter()
+            .any(|(name, _)| name.starts_with("xlink:"))
+            || element.children.iter().any(|child| match child {
+                Node::Element(child) => uses_xlink(child),
+                Node::Text(_) => false,
+            })
+    }
+
+    let mut root = root.clone();
+    let mut namespaces = vec![("xmlns".to_string(), SVG_NAMESPACE.to_string())];
+    if uses_xlink(&root) {
+        namespaces.push(("xmlns:xlink".to_string(), XLINK_NAMESPACE.to_string()));
+    }
+    root.attributes.splice(0..0, namespaces);
+    to_markup(&[Node::Element(root)])
+}
+
+/// Serializes nodes back to markup, with double-quoted attributes and explicit
+/// closing tags.
+pub(crate) fn to_markup(nodes: &[Node]) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        write_node(&mut out, node);
+    }
+    out
+}
+
+fn write_node(out: &mut String, node: &Node) {
+    match node {
+        Node::Text(text) => escape(out, text, false),
+        Node::Element(e) => {
+            out.push('<');
+            out.push_str(&e.name);
+            for (name, value) in &e.attributes {
+                out.push(' ');
+                out.push_str(name);
+                out.push_str("=\"");
+                escape(out, value, true);
+                out.push('"');
+            }
+            out.push('>');
+            for child in &e.children {
+                write_node(out, child);
+            }
+            out.push_str("</");
+            out.push_str(&e.name);
+            out.push('>');
+        }
+    }
+}
+
+fn escape(out: &mut String, text: &str, attribute: bool) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' if !attribute => out.push_str("&gt;"),
+            '"' if attribute => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_text_and_its_whitespace() {
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 20 20"><text x="1" y="10">  two  spaces &amp; a <tspan>tail</tspan> </text></svg>"#;
+        assert_eq!(to_document(&parse(svg).unwrap()), svg);
+    }
+
+    #[test]
+    fn escapes_attributes() {
+        let mut root = parse(r#"<svg xmlns="http://www.w3.org/2000/svg"/>"#).unwrap();
+        root.set_attribute("aria-label", r#"a "b" & <c>"#.to_string());
+        assert_eq!(
+            to_document(&root),
+            r#"<svg xmlns="http://www.w3.org/2000/svg" aria-label="a &quot;b&quot; &amp; &lt;c>"></svg>"#
+        );
+
+        let text = r#"<svg xmlns="http://www.w3.org/2000/svg" data-x="&quot;&amp;&lt;"></svg>"#;
+        assert_eq!(to_document(&parse(text).unwrap()), text);
+    }
+
+    #[test]
+    fn declares_the_xlink_namespace_when_it_is_used() {
+        let svg = r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink"><use xlink:href="#a"></use></svg>"##;
+        assert_eq!(to_document(&parse(svg).unwrap()), svg);
+    }
+
+    #[test]
+    fn falls_back_to_width_and_height_without_a_view_box() {
+        let view_box = |svg| parse(svg).unwrap().view_box().unwrap();
+        assert_eq!(
+            view_box(r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="16"/>"#),
+            Some((0.0, 0.0, 24.0, 16.0))
+        );
+        assert_eq!(
+            view_box(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="1 2 3 4" width="24" height="16"/>"#
+            ),
+            Some((1.0, 2.0, 3.0, 4.0))
+        );
+        // Units other than user units don't say how big the drawing is.
+        assert_eq!(
+            view_box(r#"<svg xmlns="http://www.w3.org/2000/svg" width="2em" height="16"/>"#),
+            None
+        );
+        assert!(matches!(
+            parse(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 x"/>"#)
+                .unwrap()
+                .view_box(),
+            Err(Error::ViewBox(_))
+        ));
+    }
+}
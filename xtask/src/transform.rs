@@ -0,0 +1,527 @@
+//! Applies `transform`s to the geometry they move, so the generated markup
+//! carries none. Elements that can't be rewritten keep a single `matrix()`.
+
+use std::f64::consts::PI;
+
+use crate::svg::{self, Element, Segment};
+
+/// How close two numbers have to be to count as equal.
+const EPSILON: f64 = 1e-9;
+
+/// An affine transform, as in `matrix(a b c d e f)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Transform {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    e: f64,
+    f: f64,
+}
+
+impl Transform {
+    pub const IDENTITY: Self = Self::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
+    pub const fn new(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Self {
+        Self { a, b, c, d, e, f }
+    }
+
+    /// The transform that applies `inner` first and then `self`.
+    pub fn then_inner(self, inner: Self) -> Self {
+        Self::new(
+            self.a * inner.a + self.c * inner.b,
+            self.b * inner.a + self.d * inner.b,
+            self.a * inner.c + self.c * inner.d,
+            self.b * inner.c + self.d * inner.d,
+            self.a * inner.e + self.c * inner.f + self.e,
+            self.b * inner.e + self.d * inner.f + self.f,
+        )
+    }
+
+    fn apply(self, x: f64, y: f64) -> (f64, f64) {
+        (
+            self.a * x + self.c * y + self.e,
+            self.b * x + self.d * y + self.f,
+        )
+    }
+
+    fn determinant(self) -> f64 {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// How much lengths grow, exact for uniform scales and rotations.
+    fn length_scale(self) -> f64 {
+        self.determinant().abs().sqrt()
+    }
+
+    fn is_identity(self) -> bool {
+        let identity = Self::IDENTITY;
+        [
+            self.a - identity.a,
+            self.b - identity.b,
+            self.c - identity.c,
+            self.d - identity.d,
+            self.e - identity.e,
+            self.f - identity.f,
+        ]
+        .iter()
+        .all(|delta| delta.abs() < EPSILON)
+    }
+
+    /// Keeps axis-aligned boxes axis-aligned: no rotation, or a quarter turn.
+    fn keeps_axes(self) -> bool {
+        (self.b.abs() < EPSILON && self.c.abs() < EPSILON)
+            || (self.a.abs() < EPSILON && self.d.abs() < EPSILON)
+    }
+
+    /// Only scales uniformly, rotates, reflects and translates, so circles
+    /// stay circles.
+    fn is_similarity(self) -> bool {
+        ((self.a - self.d).abs() < EPSILON && (self.b + self.c).abs() < EPSILON)
+            || ((self.a + self.d).abs() < EPSILON && (self.b - self.c).abs() < EPSILON)
+    }
+
+    /// How the x and y extents of an axis-aligned box map onto the new axes.
+    /// Only meaningful when [`keeps_axes`](Self::keeps_axes) holds.
+    fn axis_scales(self) -> (f64, f64, bool) {
+        if self.b.abs() < EPSILON && self.c.abs() < EPSILON {
+            (self.a.abs(), self.d.abs(), false)
+        } else {
+            (self.c.abs(), self.b.abs(), true)
+        }
+    }
+}
+
+impl std::str::FromStr for Transform {
+    type Err = svgtypes::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let t: svgtypes::Transform = s.parse()?;
+        Ok(Self::new(t.a, t.b, t.c, t.d, t.e, t.f))
+    }
+}
+
+/// The inherited properties that depend on the coordinate system.
+#[derive(Clone, Default)]
+struct Inherited {
+    painted: bool,
+    width: Option<String>,
+    dasharray: Option<String>,
+    dashoffset: Option<String>,
+    /// Whether the fill or the stroke is a gradient or a pattern.
+    fill_server: bool,
+    stroke_server: bool,
+}
+
+/// Moves `transform` and every `transform` attribute in the children of
+/// `root` into the coordinates of the elements they apply to.
+pub(crate) fn bake(root: &mut Element, transform: Transform) -> Result<(), svg::Error> {
+    let inherited = Inherited::default().inherit(root);
+    for child in root.elements_mut() {
+        bake_element(child, transform, &inherited)?;
+    }
+    Ok(())
+}
+
+fn bake_element(
+    element: &mut Element,
+    parent: Transform,
+    inherited: &Inherited,
+) -> Result<(), svg::Error> {
+    let own = match element.remove_attribute("transform") {
+        Some(value) => value
+            .parse::<Transform>()
+            .map_err(|_| svg::Error::Transform(value))?,
+        None => Transform::IDENTITY,
+    };
+    let transform = parent.then_inner(own);
+    let inherited = inherited.clone().inherit(element);
+
+    // Clip paths, masks, filters and paint servers are drawn in the
+    // coordinates of the elements that reference them, and the contents of
+    // `<defs>` in those of the `<use>` elements, so neither side of a
+    // reference can be rewritten.
+    let references = ["clip-path", "mask", "filter"]
+        .iter()
+        .any(|name| element.property(name).is_some_and(|value| value != "none"))
+        || inherited.fill_server
+        || inherited.stroke_server;
+    if element.name == "defs" {
+        return Ok(());
+    }
+
+    // A group passes its transform on to its children, unless something it
+    // references keeps it in place. Either way, the transforms further down
+    // are baked into the children.
+    let is_group = matches!(element.name.as_str(), "g" | "a" | "switch");
+    let mut inner = Transform::IDENTITY;
+    if is_group && !references {
+        inner = transform;
+    } else if transform.is_identity() {
+        // Nothing to apply.
+    } else if !references && bake_shape(element, transform).is_some() {
+        if inherited.painted {
+            scale_stroke(element, &inherited, transform.length_scale());
+        }
+    } else {
+        let Transform { a, b, c, d, e, f } = transform;
+        element.set_attribute("transform", format!("matrix({a} {b} {c} {d} {e} {f})"));
+    }
+
+    if is_group {
+        for child in element.elements_mut() {
+            bake_element(child, inner, &inherited)?;
+        }
+    }
+    Ok(())
+}
+
+impl Inherited {
+    fn inherit(mut self, element: &Element) -> Self {
+        if let Some(paint) = element.property("fill") {
+            self.fill_server = paint.contains("url(");
+        }
+        if let Some(paint) = element.property("stroke") {
+            self.painted = paint != "none";
+            self.stroke_server = paint.contains("url(");
+        }
+        for (name, value) in [
+            ("stroke-width", &mut self.width),
+            ("stroke-dasharray", &mut self.dasharray),
+            ("stroke-dashoffset", &mut self.dashoffset),
+        ] {
+            if let Some(own) = element.property(name) {
+                *value = Some(own);
+            }
+        }
+        self
+    }
+}
+
+/// Stroke widths and dashes are drawn in the coordinate system of the element,
+/// so they grow with the transform that has just been removed. Non-uniform
+/// scales are approximated by their average.
+fn scale_stroke(element: &mut Element, stroke: &Inherited, scale: f64) {
+    if (scale - 1.0).abs() < EPSILON {
+        return;
+    }
+    let width = stroke.width.as_deref().unwrap_or("1");
+    for (name, value) in [
+        ("stroke-width", Some(width)),
+        ("stroke-dasharray", stroke.dasharray.as_deref()),
+        ("stroke-dashoffset", stroke.dashoffset.as_deref()),
+    ] {
+        let Some(value) = value.filter(|v| *v != "none") else {
+            continue;
+        };
+        let numbers: Option<Vec<String>> = value
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|n| !n.is_empty())
+            .map(|n| n.parse::<f64>().ok().map(|n| (n * scale).to_string()))
+            .collect();
+        if let Some(numbers) = numbers {
+            element.set_property(name, numbers.join(" "));
+        }
+    }
+}
+
+/// Rewrites the geometry of a shape. Returns `None`, leaving the element
+/// untouched, if its attributes aren't plain numbers.
+fn bake_shape(element: &mut Element, t: Transform) -> Option<()> {
+    let number = |element: &Element, name: &str| -> Option<f64> {
+        match element.attribute(name) {
+            Some(value) => value.trim().parse().ok(),
+            None => Some(0.0),
+        }
+    };
+
+    match element.name.as_str() {
+        "path" => {
+            let segments = svg::parse_path(element.attribute("d").unwrap_or(""))?;
+            element.set_attribute("d", transform_path(&segments, t));
+        }
+        "line" => {
+            let (x1, y1) = t.apply(number(element, "x1")?, number(element, "y1")?);
+            let (x2, y2) = t.apply(number(element, "x2")?, number(element, "y2")?);
+            for (name, value) in [("x1", x1), ("y1", y1), ("x2", x2), ("y2", y2)] {
+                element.set_attribute(name, value.to_string());
+            }
+        }
+        "polyline" | "polygon" => {
+            let points: Vec<String> =
+                svgtypes::PointsParser::from(element.attribute("points").unwrap_or(""))
+                    .flat_map(|(x, y)| {
+                        let (x, y) = t.apply(x, y);
+                        [x.to_string(), y.to_string()]
+                    })
+                    .collect();
+            element.set_attribute("points", points.join(" "));
+        }
+        "rect" => {
+            let (x, y) = (number(element, "x")?, number(element, "y")?);
+            let (w, h) = (number(element, "width")?, number(element, "height")?);
+            // A missing corner radius takes the value of the other one.
+            let rx = element.attribute("rx").map(|_| number(element, "rx"));
+            let ry = element.attribute("ry").map(|_| number(element, "ry"));
+            let (rx, ry) = match (rx, ry) {
+                (Some(rx), Some(ry)) => (rx?, ry?),
+                (Some(r), None) | (None, Some(r)) => (r?, r?),
+                (None, None) => (0.0, 0.0),
+            };
+            let (rx, ry) = (rx.min(w / 2.0), ry.min(h / 2.0));
+
+            if t.keeps_axes() {
+                let (x1, y1) = t.apply(x, y);
+                let (x2, y2) = t.apply(x + w, y + h);
+                let (sx, sy, swapped) = t.axis_scales();
+                let (rx, ry) = if swapped {
+                    (ry * sx, rx * sy)
+                } else {
+                    (rx * sx, ry * sy)
+                };
+                element.set_attribute("x", x1.min(x2).to_string());
+                element.set_attribute("y", y1.min(y2).to_string());
+                element.set_attribute("width", (x1 - x2).abs().to_string());
+                element.set_attribute("height", (y1 - y2).abs().to_string());
+                if element.attribute("rx").is_some() || element.attribute("ry").is_some() {
+                    element.set_attribute("rx", rx.to_string());
+                    element.set_attribute("ry", ry.to_string());
+                }
+            } else {
+                let segments = rect_path(x, y, w, h, rx, ry);
+                into_path(element, &["x", "y", "width", "height", "rx", "ry"]);
+                element.set_attribute("d", transform_path(&segments, t));
+            }
+        }
+        "circle" | "ellipse" => {
+            let (cx, cy) = (number(element, "cx")?, number(element, "cy")?);
+            let (rx, ry) = if element.name == "circle" {
+                let r = number(element, "r")?;
+                (r, r)
+            } else {
+                (number(element, "rx")?, number(element, "ry")?)
+            };
+            let (cx2, cy2) = t.apply(cx, cy);
+
+            if element.name == "circle" && t.is_similarity() {
+                element.set_attribute("cx", cx2.to_string());
+                element.set_attribute("cy", cy2.to_string());
+                element.set_attribute("r", (rx * t.length_scale()).to_string());
+            } else if t.keeps_axes() {
+                let (sx, sy, swapped) = t.axis_scales();
+                let (rx, ry) = if swapped {
+                    (ry * sx, rx * sy)
+                } else {
+                    (rx * sx, ry * sy)
+                };
+                into_ellipse(element);
+                element.set_attribute("cx", cx2.to_string());
+                element.set_attribute("cy", cy2.to_string());
+                element.set_attribute("rx", rx.to_string());
+                element.set_attribute("ry", ry.to_string());
+            } else {
+                let segments = ellipse_path(cx, cy, rx, ry);
+                into_path(element, &["cx", "cy", "r", "rx", "ry"]);
+                element.set_attribute("d", transform_path(&segments, t));
+            }
+        }
+        _ => return None,
+    }
+    Some(())
+}
+
+fn into_path(element: &mut Element, geometry: &[&str]) {
+    element.name = "path".to_string();
+    element
+        .attributes
+        .retain(|(name, _)| !geometry.contains(&name.as_str()));
+}
+
+fn into_ellipse(element: &mut Element) {
+    if element.name == "circle" {
+        element.name = "ellipse".to_string();
+        element.remove_attribute("r");
+    }
+}
+
+fn rect_path(x: f64, y: f64, w: f64, h: f64, rx: f64, ry: f64) -> Vec<Segment> {
+    if rx <= 0.0 || ry <= 0.0 {
+        return vec![
+            Segment::MoveTo(x, y),
+            Segment::LineTo(x + w, y),
+            Segment::LineTo(x + w, y + h),
+            Segment::LineTo(x, y + h),
+            Segment::ClosePath,
+        ];
+    }
+    let corner = |to| Segment::Arc {
+        radii: [rx, ry, 0.0],
+        large_arc: false,
+        sweep: true,
+        to,
+    };
+    vec![
+        Segment::MoveTo(x + rx, y),
+        Segment::LineTo(x + w - rx, y),
+        corner((x + w, y + ry)),
+        Segment::LineTo(x + w, y + h - ry),
+        corner((x + w - rx, y + h)),
+        Segment::LineTo(x + rx, y + h),
+        corner((x, y + h - ry)),
+        Segment::LineTo(x, y + ry),
+        corner((x + rx, y)),
+        Segment::ClosePath,
+    ]
+}
+
+fn ellipse_path(cx: f64, cy: f64, rx: f64, ry: f64) -> Vec<Segment> {
+    let half = |to| Segment::Arc {
+        radii: [rx, ry, 0.0],
+        large_arc: true,
+        sweep: false,
+        to,
+    };
+    vec![
+        Segment::MoveTo(cx - rx, cy),
+        half((cx + rx, cy)),
+        half((cx - rx, cy)),
+        Segment::ClosePath,
+    ]
+}
+
+fn transform_path(segments: &[Segment], t: Transform) -> String {
+    let segments: Vec<Segment> = segments
+        .iter()
+        .map(|segment| match *segment {
+            Segment::Arc {
+                radii,
+                large_arc,
+                sweep,
+                to,
+            } => Segment::Arc {
+                radii: transform_radii(radii, t),
+                large_arc,
+                // A reflection reverses the direction of the arc.
+                sweep: sweep != (t.determinant() < 0.0),
+                to: t.apply(to.0, to.1),
+            },
+            segment => segment.map_points(|x, y| t.apply(x, y)),
+        })
+        .collect();
+    svg::path_data(&segments)
+}
+
+/// The radii and rotation of the ellipse an arc lies on, after `t`.
+///
+/// The ellipse is the image of the unit circle under `E = R(rotation) ·
+/// diag(rx, ry)`, so after the transform its axes are the eigenvectors of
+/// `(TE)(TE)ᵀ` and its radii the square roots of the eigenvalues.
+fn transform_radii([rx, ry, rotation]: [f64; 3], t: Transform) -> [f64; 3] {
+    if rx == 0.0 || ry == 0.0 {
+        return [rx, ry, rotation];
+    }
+    let (sin, cos) = (rotation * PI / 180.0).sin_cos();
+    // The columns of TE.
+    let (m00, m10) = ((t.a * cos + t.c * sin) * rx, (t.b * cos + t.d * sin) * rx);
+    let (m01, m11) = ((-t.a * sin + t.c * cos) * ry, (-t.b * sin + t.d * cos) * ry);
+    let p = m00 * m00 + m01 * m01;
+    let q = m00 * m10 + m01 * m11;
+    let r = m10 * m10 + m11 * m11;
+
+    let mean = (p + r) / 2.0;
+    let spread = (((p - r) / 2.0).powi(2) + q * q).sqrt();
+    let major = (mean + spread).sqrt();
+    let minor = (mean - spread).max(0.0).sqrt();
+    let angle = if spread < EPSILON {
+        0.0
+    } else {
+        0.5 * (2.0 * q).atan2(p - r) * 180.0 / PI
+    };
+    [major, minor, angle]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::svg::Node;
+
+    /// Bakes the transforms of a document and returns its first drawn element.
+    fn baked(svg: &str) -> Element {
+        fn first_shape(element: &Element) -> Option<&Element> {
+            element.children.iter().find_map(|child| match child {
+                Node::Element(e) if e.name == "g" => first_shape(e),
+                Node::Element(e) => Some(e),
+                Node::Text(_) => None,
+            })
+        }
+
+        let mut root = svg::parse(svg).unwrap();
+        bake(&mut root, Transform::IDENTITY).unwrap();
+        first_shape(&root).unwrap().clone()
+    }
+
+    #[test]
+    fn bakes_transforms_under_a_group_without_one() {
+        let rect = baked(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><g><rect transform="translate(24 24) rotate(180)" width="4" height="2"/></g></svg>"#,
+        );
+        assert_eq!(rect.attribute("transform"), None);
+        assert_eq!(rect.attribute("x"), Some("20"));
+        assert_eq!(rect.attribute("y"), Some("22"));
+        assert_eq!(rect.attribute("width"), Some("4"));
+        assert_eq!(rect.attribute("height"), Some("2"));
+    }
+
+    #[test]
+    fn bakes_nested_transforms() {
+        let path = baked(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><g><g transform="translate(1 0)"><g><path transform="scale(2)" d="M1 1L2 3"/></g></g></g></svg>"#,
+        );
+        assert_eq!(path.attribute("transform"), None);
+        assert_eq!(path.attribute("d"), Some("M 3 2 L 5 6"));
+    }
+
+    #[test]
+    fn scales_the_stroke_width_with_the_transform() {
+        let path = baked(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><g transform="scale(2)" stroke="black" stroke-width="1.5" stroke-dasharray="1 2"><path d="M0 0L1 0"/></g></svg>"#,
+        );
+        assert_eq!(path.attribute("stroke-width"), Some("3"));
+        assert_eq!(path.attribute("stroke-dasharray"), Some("2 4"));
+
+        let unstroked = baked(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><g transform="scale(2)"><path d="M0 0L1 0"/></g></svg>"#,
+        );
+        assert_eq!(unstroked.attribute("stroke-width"), None);
+    }
+
+    #[test]
+    fn keeps_the_transform_of_elements_that_reference_others() {
+        let mut root = svg::parse(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><g transform="translate(2 3)" clip-path="url(#c)"><path transform="translate(1 1)" d="M0 0L1 0"/></g></svg>"#,
+        )
+        .unwrap();
+        bake(&mut root, Transform::IDENTITY).unwrap();
+        let group = root.elements_mut().next().unwrap();
+        assert_eq!(group.attribute("transform"), Some("matrix(1 0 0 1 2 3)"));
+        let Some(Node::Element(path)) = group.children.first() else {
+            panic!("the path is gone");
+        };
+        assert_eq!(path.attribute("transform"), None);
+        assert_eq!(path.attribute("d"), Some("M 1 1 L 2 1"));
+    }
+
+    #[test]
+    fn keeps_the_transform_of_elements_painted_with_a_gradient() {
+        let path = baked(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><g transform="translate(2 3)"><path fill="url(#a)" d="M0 0L1 0"/></g></svg>"#,
+        );
+        assert_eq!(path.attribute("transform"), Some("matrix(1 0 0 1 2 3)"));
+        assert_eq!(path.attribute("d"), Some("M0 0L1 0"));
+
+        let path = baked(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><g style="stroke: url(#a)"><path transform="translate(2 3)" d="M0 0L1 0"/></g></svg>"#,
+        );
+        assert_eq!(path.attribute("transform"), Some("matrix(1 0 0 1 2 3)"));
+    }
+}
@@ -1,11 +1,15 @@
 use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use regex::Regex;
 use std::collections::{BTreeMap, HashMap};
 use std::{fs, process};
 
-fn extract_categories(input: &str) -> (HashMap<String, Vec<String>>, BTreeMap<String, ()>) {
+use crate::transform::{self, Transform};
+use crate::{optimize, sanitize, svg};
+
+pub(crate) fn extract_categories(
+    input: &str,
+) -> (HashMap<String, Vec<String>>, BTreeMap<String, ()>) {
     let mut icon_categories: HashMap<String, Vec<String>> = HashMap::new();
     let mut categories_set: BTreeMap<String, ()> = BTreeMap::new();
 
@@ -29,6 +33,70 @@ fn extract_categories(input: &str) -> (HashMap<String, Vec<String>>, BTreeMap<St
     (icon_categories, categories_set)
 }
 
+/// The per-icon record from the typescript export file.
+pub(crate) struct IconMetadata {
+    pub(crate) pascal_name: Option<String>,
+    pub(crate) styles: Vec<String>,
+    weight: Option<String>,
+    categories: Vec<String>,
+    pub(crate) tags: Vec<String>,
+    pub(crate) codepoint: Option<u32>,
+    published_in: Option<String>,
+    updated_in: Option<String>,
+}
+
+pub(crate) fn extract_metadata(input: &str) -> HashMap<String, IconMetadata> {
+    fn strings(data: &serde_json::Value, key: &str) -> Vec<String> {
+        data.get(key)
+            .and_then(|v| v.as_array())
+            .map(|list| {
+                list.iter()
+                    .filter_map(|v| v.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// A release as written in the file, e.g. `1.0`.
+    fn version(data: &serde_json::Value, key: &str) -> Option<String> {
+        match data.get(key)? {
+            serde_json::Value::Number(version) => Some(version.to_string()),
+            serde_json::Value::String(version) => Some(version.clone()),
+            _ => None,
+        }
+    }
+
+    let mut icon_metadata = HashMap::new();
+
+    let parsed: serde_json::Value = serde_json::from_str(input).unwrap();
+    if let serde_json::Value::Object(map) = parsed {
+        for (icon_name, data) in map {
+            let metadata = IconMetadata {
+                pascal_name: data
+                    .get("pascal_name")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string),
+                styles: strings(&data, "styles"),
+                weight: data
+                    .get("weight")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string),
+                categories: strings(&data, "categories"),
+                tags: strings(&data, "tags"),
+                codepoint: data
+                    .get("codepoint")
+                    .and_then(|v| v.as_u64())
+                    .map(|v| v as u32),
+                published_in: version(&data, "published_in"),
+                updated_in: version(&data, "updated_in"),
+            };
+            icon_metadata.insert(icon_name, metadata);
+        }
+    }
+
+    icon_metadata
+}
+
 fn cargo_template(features: &BTreeMap<String, ()>) -> String {
     let mut template = r#"# GENERATED FILE!
 # Edit xtask/src/update.rs to maintain this file
@@ -48,123 +116,413 @@ exclude = ["/core"]
 # See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
 
 [dependencies]
-leptos = { version = "0.7.8", features = ["csr", "nightly"] }
+dioxus = { version = "0.6.3", optional = true, default-features = false, features = ["macro", "html", "signals", "hooks"] }
+leptos = { version = "0.7.8", optional = true }
+lumo-icons-core = { version = "0.8.0", path = "lumo-icons-core", default-features = false }
 serde_json = "1"
+web-sys = { version = "0.3", optional = true, features = ["console", "Document", "Element", "HtmlHeadElement", "Node", "Window"] }
+yew = { version = "0.21.0", optional = true }
 
 [workspace]
-members = ["xtask"]
+members = ["lumo-icons-core", "xtask"]
+
+[features]
+default = ["all", "leptos"]
+
+# The Leptos `Icon` and `IconPicker` components.
+leptos = ["dep:leptos"]
+
+# The `dioxus::Icon` and `yew::Icon` components. Unlike Leptos, they don't
+# expose `web-sys`, which they need for warnings and the animation stylesheet.
+dioxus = ["dep:dioxus", "dep:web-sys"]
+yew = ["dep:yew", "dep:web-sys"]
+
+# Build the Leptos `Icon` and `SpriteSheet` elements node by node instead of
+# assigning `inner_html`, for pages that enforce Trusted Types.
+trusted-types = ["lumo-icons-core/nodes"]
+
+# Leptos rendering modes; enable the one matching your application.
+csr = ["leptos/csr"]
+hydrate = ["leptos/hydrate"]
+ssr = ["leptos/ssr"]
+nightly = ["leptos/nightly"]
+
+"#
+    .to_string();
+
+    // The icon sets are forwarded to the core crate that holds the icons
+    template.push_str(&features_template(features, Some("lumo-icons-core")));
+
+    template
+}
+
+fn core_cargo_template(features: &BTreeMap<String, ()>) -> String {
+    let mut template = r#"# GENERATED FILE!
+# Edit xtask/src/update.rs to maintain this file
+
+[package]
+name = "lumo-icons-core"
+version = "0.8.0"
+description = "Framework-agnostic, no_std icon data for lumo-icons"
+authors = ["James <lumo.trade>"]
+repository = "https://github.com/cornfoo/lumo-icons"
+keywords = ["icons", "no_std", "lumo"]
+edition = "2021"
+license = "MIT"
+
+[dependencies]
 
 [features]
 default = ["all"]
+
+# Each icon's markup as a tree of `SvgNode`s, to build its elements from.
+nodes = []
+
 "#
     .to_string();
 
+    template.push_str(&features_template(features, None));
+
+    template
+}
+
+fn features_template(features: &BTreeMap<String, ()>, forward_to: Option<&str>) -> String {
+    let mut template = String::new();
+
     // Add in the "all" feature
     template.push_str(&format!(
         "all = [\n{}\n]\n",
         features
-            .iter()
-            .map(|(feature, _)| format!("\t\"{feature}\""))
+            .keys()
+            .map(|feature| format!("\t\"{feature}\""))
             .collect::<Vec<_>>()
             .join(",\n"),
     ));
 
     // now add the rest, read from the icon_categories
     for feature in features.keys() {
-        template.push_str(&format!("{feature} = []\n"));
+        match forward_to {
+            Some(dependency) => {
+                template.push_str(&format!("{feature} = [\"{dependency}/{feature}\"]\n"))
+            }
+            None => template.push_str(&format!("{feature} = []\n")),
+        }
     }
 
     template
 }
 
+fn pascal_name(icon_name: &str, metadata: Option<&IconMetadata>) -> String {
+    metadata
+        .and_then(|m| m.pascal_name.clone())
+        .unwrap_or_else(|| icon_name.to_case(Case::Pascal))
+}
+
+fn meta_template(icon_name: &str, metadata: Option<&IconMetadata>, sets: &[String]) -> TokenStream {
+    fn optional<T: quote::ToTokens>(value: Option<T>) -> TokenStream {
+        match value {
+            Some(value) => quote! { Some(#value) },
+            None => quote! { None },
+        }
+    }
+
+    let pascal_name = pascal_name(icon_name, metadata);
+    let styles = metadata.map(|m| m.styles.as_slice()).unwrap_or_default();
+    let weight = optional(metadata.and_then(|m| m.weight.as_deref()));
+    let categories = metadata
+        .map(|m| m.categories.as_slice())
+        .unwrap_or_default();
+    let tags = metadata.map(|m| m.tags.as_slice()).unwrap_or_default();
+    let codepoint = optional(metadata.and_then(|m| m.codepoint));
+    let published_in = optional(metadata.and_then(|m| m.published_in.as_deref()));
+    let updated_in = optional(metadata.and_then(|m| m.updated_in.as_deref()));
+
+    quote! {
+        crate::IconMeta {
+            name: #icon_name,
+            pascal_name: #pascal_name,
+            sets: &[#(#sets),*],
+            styles: &[#(#styles),*],
+            weight: #weight,
+            categories: &[#(#categories),*],
+            tags: &[#(#tags),*],
+            codepoint: #codepoint,
+            published_in: #published_in,
+            updated_in: #updated_in,
+        }
+    }
+}
+
 fn icon_template(
     icon_name: &str,
-    icon_styles: impl Iterator<Item = (String, String)>,
+    icon_styles: impl Iterator<Item = (String, Option<NormalizedSvg>)>,
+    icon_meta: TokenStream,
 ) -> TokenStream {
     let component_ident = format_ident!("{}", icon_name.to_case(Case::UpperSnake));
-    let styles = icon_styles.map(|s| s.1);
+    let meta_ident = format_ident!("{}_META", icon_name.to_case(Case::UpperSnake));
+    let svgs: Vec<_> = icon_styles.map(|s| s.1).collect();
+
+    // Styles the icon isn't drawn in get the view box of its first style, so
+    // a blank icon still takes up the same space.
+    let fallback = svgs
+        .iter()
+        .flatten()
+        .map(|svg| svg.view_box)
+        .next()
+        .unwrap_or_default();
+    let styles = svgs
+        .iter()
+        .map(|svg| svg.as_ref().map_or("", |svg| svg.markup.as_str()));
+    let view_boxes = svgs.iter().map(|svg| {
+        let (x, y, w, h) = svg.as_ref().map_or(fallback, |svg| svg.view_box);
+        quote! { crate::ViewBox::new(#x, #y, #w, #h) }
+    });
+    let nodes = svgs
+        .iter()
+        .map(|svg| nodes_template(svg.as_ref().map_or(&[], |svg| svg.nodes.as_slice())));
 
     quote! {
         //! GENERATED FILE
-        pub const #component_ident: &crate::IconStyleData = &crate::IconStyleData([#(#styles),*]);
+        pub const #meta_ident: &crate::IconMeta = &#icon_meta;
+        pub const #component_ident: &crate::IconStyleData = &crate::IconStyleData {
+            markup: [#(#styles),*],
+            view_boxes: [#(#view_boxes),*],
+            meta: #meta_ident,
+            #[cfg(feature = "nodes")]
+            nodes: [#(#nodes),*],
+        };
     }
 }
 
-const OUTPUT_DIR: &str = "src/icons";
-const ASSETS_DIR: &str = "assets";
-const TYPESCRIPT_EXPORT_FILE: &str = "metadata/icons.json";
-
-// Critical: normalize any non-native viewBox content to a common canvas so icons render at the correct scale.
-fn parse_viewbox(raw: &str) -> Option<(f32, f32)> {
-    let re = Regex::new(r#"(?i)viewBox\s*=\s*"[^"]*?0\s+0\s+([0-9.]+)\s+([0-9.]+)""#).unwrap();
-    re.captures(raw).map(|c| {
-        let w = c[1].parse::<f32>().unwrap_or(256.0);
-        let h = c[2].parse::<f32>().unwrap_or(256.0);
-        (w, h)
-    })
+/// The markup of an icon as a tree of `SvgNode`s.
+fn nodes_template(nodes: &[svg::Node]) -> TokenStream {
+    let nodes = nodes.iter().map(|node| match node {
+        svg::Node::Element(element) => {
+            let name = &element.name;
+            let attribute_names = element.attributes.iter().map(|(name, _)| name);
+            let attribute_values = element.attributes.iter().map(|(_, value)| value);
+            let children = nodes_template(&element.children);
+            quote! {
+                crate::SvgNode::Element {
+                    name: #name,
+                    attributes: &[#((#attribute_names, #attribute_values)),*],
+                    children: #children,
+                }
+            }
+        }
+        svg::Node::Text(text) => quote! { crate::SvgNode::Text(#text) },
+    });
+    quote! { &[#(#nodes),*] }
+}
+
+const CORE_DIR: &str = "lumo-icons-core";
+const OUTPUT_DIR: &str = "lumo-icons-core/src/icons";
+pub(crate) const ASSETS_DIR: &str = "assets";
+pub(crate) const TYPESCRIPT_EXPORT_FILE: &str = "metadata/icons.json";
+
+// The exported titles ("clone 2 fill") are file names rather than descriptions, and
+// screen readers would announce them. Icons get their accessible name from the component.
+fn strip_titles(element: &mut svg::Element) {
+    element
+        .children
+        .retain(|child| !matches!(child, svg::Node::Element(e) if e.name == "title"));
+    element.elements_mut().for_each(strip_titles);
 }
 
-fn strip_svg_outer(raw: &str) -> String {
-    let svg_open = Regex::new(r"(?is)<svg[^>]*>").unwrap();
-    let svg_close = Regex::new(r"(?is)</svg>").unwrap();
-    svg_close
-        .replace(&svg_open.replace(raw, ""), "")
-        .to_string()
+// Layers tagged `data-color="color-2"` are the secondary half of duotone icons. Their
+// paint and opacity come from the custom properties that `Icon` sets from its
+// `secondary_color` and `secondary_opacity` props, falling back to the primary color
+// and the opacity they were drawn with. Presentation attributes can't use `var()`,
+// so they move into the element's `style`.
+fn secondary_layers(element: &mut svg::Element, inherited: (Option<&str>, Option<&str>)) {
+    let fill = element.property("fill").or(inherited.0.map(str::to_string));
+    let stroke = element
+        .property("stroke")
+        .or(inherited.1.map(str::to_string));
+    if element.attribute("data-color") == Some("color-2") {
+        secondary_layer(element, inherited);
+    }
+    for child in element.elements_mut() {
+        secondary_layers(child, (fill.as_deref(), stroke.as_deref()));
+    }
 }
 
-fn normalize_colors_to_current(inner: &str) -> String {
-    let fill_attr = Regex::new(r#"(?i)fill\s*=\s*"([^"]+)""#).unwrap();
-    let s = fill_attr.replace_all(inner, |caps: &regex::Captures| {
-        let v = &caps[1];
-        if v.eq_ignore_ascii_case("none") || v.eq_ignore_ascii_case("currentcolor") {
-            caps[0].to_string()
+fn secondary_layer(element: &mut svg::Element, inherited: (Option<&str>, Option<&str>)) {
+    const COLOR: &str = "var(--lumo-icon-secondary-color, currentColor)";
+
+    let mut style = element
+        .remove_attribute("style")
+        .map_or_else(Vec::new, |style| svg::declarations(&style));
+    let mut paint = HashMap::new();
+    for name in [
+        "fill",
+        "stroke",
+        "fill-opacity",
+        "stroke-opacity",
+        "opacity",
+    ] {
+        let declared = style
+            .iter()
+            .rposition(|(n, _)| n == name)
+            .map(|i| style[i].1.clone());
+        style.retain(|(n, _)| n != name);
+        let Some(value) = declared.or_else(|| element.attribute(name).map(str::to_string)) else {
+            continue;
+        };
+        // An unpainted fill or stroke stays a plain attribute.
+        if matches!(name, "fill" | "stroke") && value == "none" {
+            element.set_attribute(name, value.clone());
         } else {
-            r#"fill="currentColor""#.to_string()
+            element.remove_attribute(name);
         }
-    });
-    let stroke_attr = Regex::new(r#"(?i)stroke\s*=\s*"([^"]+)""#).unwrap();
-    let s = stroke_attr.replace_all(&s, |caps: &regex::Captures| {
-        let v = &caps[1];
-        if v.eq_ignore_ascii_case("none") || v.eq_ignore_ascii_case("currentcolor") {
-            caps[0].to_string()
-        } else {
-            r#"stroke="currentColor""#.to_string()
+        paint.insert(name, value);
+    }
+
+    // The exports set the fill on the wrapping group, so a missing fill is inherited.
+    let fill = paint.get("fill").map(String::as_str).or(inherited.0);
+    if fill != Some("none") {
+        style.push(("fill".to_string(), COLOR.to_string()));
+    }
+    let stroke = paint.get("stroke").map(String::as_str).or(inherited.1);
+    if stroke.is_some_and(|stroke| stroke != "none") {
+        style.push(("stroke".to_string(), COLOR.to_string()));
+    }
+    let fill_opacity = paint.get("fill-opacity");
+    let stroke_opacity = paint.get("stroke-opacity");
+    for (property, value) in [
+        ("fill-opacity", fill_opacity),
+        ("stroke-opacity", stroke_opacity),
+    ] {
+        if let Some(value) = value {
+            style.push((
+                property.to_string(),
+                format!("var(--lumo-icon-secondary-opacity, {value})"),
+            ));
         }
-    });
-    s.to_string()
+    }
+    if fill_opacity.is_none() && stroke_opacity.is_none() {
+        let value = paint.get("opacity").map_or("1", String::as_str);
+        style.push((
+            "opacity".to_string(),
+            format!("var(--lumo-icon-secondary-opacity, {value})"),
+        ));
+    } else if let Some(value) = paint.get("opacity") {
+        style.push(("opacity".to_string(), value.clone()));
+    }
+
+    element
+        .attributes
+        .push(("style".to_string(), svg::to_style(&style)));
 }
 
-fn normalize_svg(raw: &str, target_canvas: f32) -> String {
-    let (vw, vh) = parse_viewbox(raw).unwrap_or((target_canvas, target_canvas));
-    let src_max = vw.max(vh);
-    let scale = if (src_max - 0.0).abs() < f32::EPSILON {
-        1.0
-    } else {
-        target_canvas / src_max
+fn normalize_colors_to_current(element: &mut svg::Element) {
+    // The secondary layers already point at their custom property.
+    let keep = |value: &str| {
+        value.eq_ignore_ascii_case("none")
+            || value.eq_ignore_ascii_case("currentcolor")
+            || value.starts_with("var(")
     };
 
-    let inner = strip_svg_outer(raw);
-    let inner = normalize_colors_to_current(&inner);
+    for name in ["fill", "stroke"] {
+        if element.attribute(name).is_some_and(|value| !keep(value)) {
+            element.set_attribute(name, "currentColor".to_string());
+        }
+    }
+    if let Some(style) = element.attribute("style") {
+        let mut declarations = svg::declarations(style);
+        let mut changed = false;
+        for (name, value) in &mut declarations {
+            if matches!(name.as_str(), "fill" | "stroke") && !keep(value) {
+                *value = "currentColor".to_string();
+                changed = true;
+            }
+        }
+        if changed {
+            element.set_attribute("style", svg::to_style(&declarations));
+        }
+    }
+    element.elements_mut().for_each(normalize_colors_to_current);
+}
 
-    if (scale - 1.0).abs() > f32::EPSILON {
-        format!(r#"<g transform="scale({scale})">{inner}</g>"#)
+fn size_change(before: usize, after: usize) -> String {
+    let saved = if before == 0 {
+        0.0
     } else {
-        inner
+        100.0 * (before as f64 - after as f64) / before as f64
+    };
+    format!("{before:>8} -> {after:>8} bytes ({saved:.1}% smaller)")
+}
+
+/// An asset after it went through the generator.
+pub(crate) struct NormalizedSvg {
+    pub(crate) markup: String,
+    /// The elements the markup is made of.
+    nodes: Vec<svg::Node>,
+    pub(crate) view_box: svg::ViewBox,
+    /// How many bytes the markup took up before it was optimized.
+    unoptimized_len: usize,
+}
+
+pub(crate) fn normalize_svg(raw: &str, precision: usize) -> Result<NormalizedSvg, svg::Error> {
+    let mut root = svg::parse(raw)?;
+    // Icons keep the coordinates they were drawn in, whatever their size.
+    let view_box = root.view_box()?.ok_or(svg::Error::MissingViewBox)?;
+
+    strip_titles(&mut root);
+    secondary_layers(&mut root, (None, None));
+    normalize_colors_to_current(&mut root);
+    let unoptimized_len = svg::to_markup(&root.children).len();
+    transform::bake(&mut root, Transform::IDENTITY)?;
+    optimize::optimize(&mut root, precision);
+    Ok(NormalizedSvg {
+        markup: svg::to_markup(&root.children),
+        nodes: root.children,
+        view_box,
+        unoptimized_len,
+    })
+}
+
+/// Checks every asset against the sanitizer's allow-list, exiting with a report
+/// of the offending files if any of them contain unsafe markup.
+pub(crate) fn check_assets(styles: &[String], file_names: &[String]) {
+    let mut unsafe_files = 0;
+    for style in styles {
+        for file_name in file_names {
+            let path = format!("{ASSETS_DIR}/{style}/{file_name}");
+            let Ok(svg_raw) = fs::read_to_string(&path) else {
+                continue;
+            };
+            let problems = match svg::parse(&svg_raw) {
+                Ok(root) => sanitize::check(&root),
+                Err(e) => vec![e.to_string()],
+            };
+            if !problems.is_empty() {
+                unsafe_files += 1;
+                eprintln!("{path}:");
+                for problem in problems {
+                    eprintln!("  {problem}");
+                }
+            }
+        }
+    }
+
+    if unsafe_files > 0 {
+        eprintln!(
+            "Refusing to generate icons: {unsafe_files} asset{} failed the check",
+            if unsafe_files == 1 { "" } else { "s" }
+        );
+        process::exit(1);
     }
 }
 
-pub fn run() {
+pub fn run(precision: usize) {
     // Extract the categories from the typescript export file
-    let (icon_categories, categories_set) =
-        extract_categories(fs::read_to_string(TYPESCRIPT_EXPORT_FILE).unwrap().as_str());
+    let typescript_export = fs::read_to_string(TYPESCRIPT_EXPORT_FILE).unwrap();
+    let (icon_categories, categories_set) = extract_categories(&typescript_export);
+    let icon_metadata = extract_metadata(&typescript_export);
 
     let uncategorized = vec!["uncategorized".into()];
 
-    // Clean up the icons folder
-    let _ = fs::remove_dir_all(OUTPUT_DIR);
-    fs::write("src/lib.rs", "").unwrap();
-    fs::create_dir(OUTPUT_DIR).unwrap();
-
     // Get a list of all the icon styles
     let mut styles: Vec<_> = fs::read_dir(ASSETS_DIR)
         .unwrap()
@@ -196,27 +554,19 @@ pub fn run() {
     // stable order. This should improve `src/mod.rs` diffs.
     file_names.sort_unstable();
 
-    // Determine a dynamic canvas from the largest source viewBox across all icons.
-    let mut canvas: f32 = 256.0;
-    for s in &styles {
-        if let Ok(dir) = fs::read_dir(format!("{ASSETS_DIR}/{s}")) {
-            for entry in dir.flatten() {
-                let path = entry.path();
-                if path.is_file() {
-                    if let Ok(raw) = fs::read_to_string(&path) {
-                        if let Some((w, h)) = parse_viewbox(&raw) {
-                            let m = w.max(h);
-                            if m > canvas {
-                                canvas = m;
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
+    // Refuse to embed unsafe markup before anything is overwritten
+    check_assets(&styles, &file_names);
+
+    // Clean up the icons folder
+    let _ = fs::remove_dir_all(OUTPUT_DIR);
+    fs::write(format!("{CORE_DIR}/src/lib.rs"), "").unwrap();
+    fs::write("src/lib.rs", "").unwrap();
+    fs::create_dir(OUTPUT_DIR).unwrap();
 
+    let mut byte_report: BTreeMap<String, (usize, usize)> = BTreeMap::new();
     let mut mod_content = Vec::new();
+    let mut catalog = Vec::new();
+    let mut name_arms = Vec::new();
     for file_name in file_names {
         let icon_name = file_name.strip_suffix(".svg").unwrap().to_string();
 
@@ -227,12 +577,19 @@ pub fn run() {
         let icon_styles = styles.iter().map(|style| {
             let file_name = file_name.clone();
             let path = format!("{ASSETS_DIR}/{style}/{file_name}");
-            let svg_raw = fs::read_to_string(&path).unwrap_or_default();
-            let svg = normalize_svg(&svg_raw, canvas);
+            let svg = fs::read_to_string(&path).ok().map(|svg_raw| {
+                let svg = normalize_svg(&svg_raw, precision)
+                    .unwrap_or_else(|e| panic!("Error reading {path}: {e}"));
+                let (before, after) = byte_report.entry(style.clone()).or_default();
+                *before += svg.unoptimized_len;
+                *after += svg.markup.len();
+                svg
+            });
             (style.to_string(), svg)
         });
 
-        let file = icon_template(&icon_name, icon_styles);
+        let icon_meta = meta_template(&icon_name, icon_metadata.get(&icon_name), features);
+        let file = icon_template(&icon_name, icon_styles, icon_meta);
 
         fs::write(
             format!("{OUTPUT_DIR}/{}.rs", icon_name.to_case(Case::Snake)),
@@ -241,31 +598,63 @@ pub fn run() {
         .unwrap();
 
         let mod_name = format_ident!("{}", icon_name.to_case(Case::Snake));
-        if features.len() == 1 {
+        let cfg = if features.len() == 1 {
             let feature = &features[0];
-            mod_content.push(quote! {
-                #[cfg(feature = #feature)]
-                #[doc(hidden)]
-                mod #mod_name;
-
-                #[cfg(feature = #feature)]
-                #[doc(hidden)]
-                pub use #mod_name::*;
-            });
+            quote! { feature = #feature }
         } else {
-            mod_content.push(quote! {
-                #[cfg(any(#(feature = #features),*))]
-                #[doc(hidden)]
-                mod #mod_name;
-
-                #[cfg(any(#(feature = #features),*))]
-                #[doc(hidden)]
-                pub use #mod_name::*;
-            });
-        }
+            quote! { any(#(feature = #features),*) }
+        };
+        mod_content.push(quote! {
+            #[cfg(#cfg)]
+            #[doc(hidden)]
+            mod #mod_name;
+
+            #[cfg(#cfg)]
+            #[doc(hidden)]
+            pub use #mod_name::*;
+        });
+
+        let component_ident = format_ident!("{}", icon_name.to_case(Case::UpperSnake));
+        let pascal_name = pascal_name(&icon_name, icon_metadata.get(&icon_name));
+        catalog.push(quote! {
+            #[cfg(#cfg)]
+            #component_ident,
+        });
+        name_arms.push(quote! {
+            #[cfg(#cfg)]
+            #icon_name | #pascal_name => Some(#component_ident),
+        });
+    }
+
+    println!("Optimized icon markup:");
+    let (mut total_before, mut total_after) = (0, 0);
+    for (style, (before, after)) in &byte_report {
+        println!("  {style:<16} {}", size_change(*before, *after));
+        total_before += before;
+        total_after += after;
     }
+    println!(
+        "  {:<16} {}",
+        "total",
+        size_change(total_before, total_after)
+    );
 
-    let module = quote! { #(#mod_content)* }.to_string();
+    let module = quote! {
+        #(#mod_content)*
+
+        /// Every icon enabled through Cargo features, ordered by name.
+        pub const ICONS: &[crate::IconData] = &[#(#catalog)*];
+
+        /// Looks up an icon by its kebab-case name (`gem-fill`) or its PascalCase
+        /// name (`GemFill`). Only icons enabled through Cargo features are found.
+        pub fn icon_by_name(name: &str) -> Option<crate::IconData> {
+            match name {
+                #(#name_arms)*
+                _ => None,
+            }
+        }
+    }
+    .to_string();
     fs::write(format!("{OUTPUT_DIR}/mod.rs"), module).unwrap();
 
     let style_variants: Vec<_> = styles
@@ -275,48 +664,130 @@ pub fn run() {
 
     let style_len = style_variants.len();
     let style_indices = style_variants.iter().enumerate().map(|(i, v)| {
-        quote! { IconStyle::#v => self.0[#i] }
+        quote! { IconStyle::#v => self.markup[#i] }
+    });
+    let view_box_indices = style_variants.iter().enumerate().map(|(i, v)| {
+        quote! { IconStyle::#v => self.view_boxes[#i] }
+    });
+    let node_indices = style_variants.iter().enumerate().map(|(i, v)| {
+        quote! { IconStyle::#v => self.nodes[#i] }
+    });
+
+    let style_names = styles.iter().map(|s| s.as_str());
+
+    // The first style is the default one.
+    let style_declarations = style_variants.iter().enumerate().map(|(i, v)| {
+        if i == 0 {
+            quote! { #[default] #v }
+        } else {
+            quote! { #v }
+        }
     });
 
     let default_variant = style_variants
-        .get(0)
+        .first()
         .cloned()
         .unwrap_or_else(|| format_ident!("Regular"));
 
-    let canvas_int = canvas as i32;
-
-    let lib = quote! {
-        //! Phosphor is a flexible icon family for interfaces, diagrams,
-        //! presentations — whatever, really.
-        //! You can explore the available icons at [phosphoricons.com](https://phosphoricons.com).
+    let core_lib = quote! {
+        //! The framework-agnostic core of lumo-icons: the icon data, its metadata,
+        //! search and standalone SVG rendering.
+        //!
+        //! The crate is `no_std` (it only needs `alloc`), so the icons can be used
+        //! on servers, in other UI frameworks or on embedded targets without
+        //! pulling in Leptos. The `lumo-icons` crate builds its components on top.
         //!
         //! ```
-        //! use leptos::prelude::*;
-        //! use phosphor_leptos::{Icon, IconStyle, HORSE, HEART, CUBE};
+        //! use lumo_icons_core::{render_svg, IconStyle, RenderOptions, GEM_FILL};
         //!
-        //! #[component]
-        //! fn MyComponent() -> impl IntoView {
-        //!     view! {
-        //!         <Icon icon=HORSE />
-        //!         <Icon icon=HEART color="#AE2983" style=IconStyle::Fill size="32px" />
-        //!         <Icon icon=CUBE color="teal" style=IconStyle::Duotone />
-        //!     }
-        //! }
+        //! let svg = render_svg(GEM_FILL, IconStyle::MicroBold, &RenderOptions::default());
         //! ```
-        use leptos::{prelude::*, text_prop::TextProp};
+        #![no_std]
+
+        extern crate alloc;
+
+        use alloc::string::{String, ToString};
+
+        mod animation;
+        pub use animation::*;
+
+        mod fallback;
+        pub use fallback::*;
 
         mod icons;
         pub use icons::*;
 
+        #[cfg(feature = "nodes")]
+        mod nodes;
+        #[cfg(feature = "nodes")]
+        pub use nodes::*;
+
+        mod render;
+        pub use render::*;
+
+        mod search;
+        pub use search::*;
+
+        mod sprite;
+        pub use sprite::*;
+
+        mod view_box;
+        pub use view_box::*;
+
         /// An icon's style.
-        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
         pub enum IconStyle {
-            #(#style_variants),*
+            #(#style_declarations),*
+        }
+
+        impl IconStyle {
+            /// Every style, in declaration order.
+            pub const ALL: [IconStyle; #style_len] = [#(IconStyle::#style_variants),*];
+
+            /// The kebab-case name of the style, matching its `assets` folder.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    #(IconStyle::#style_variants => #style_names),*
+                }
+            }
+        }
+
+        /// Descriptive metadata for an icon, as recorded in `metadata/icons.json`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct IconMeta {
+            /// The kebab-case name of the icon, e.g. `gem-fill`.
+            pub name: &'static str,
+            /// The PascalCase name of the icon, e.g. `GemFill`.
+            pub pascal_name: &'static str,
+            /// The sets (and therefore Cargo features) the icon belongs to.
+            pub sets: &'static [&'static str],
+            /// The styles the icon is declared to be drawn in.
+            pub styles: &'static [&'static str],
+            /// The stroke weight the icon was drawn with.
+            pub weight: Option<&'static str>,
+            /// Broad categories the icon can be grouped under.
+            pub categories: &'static [&'static str],
+            /// Search keywords associated with the icon.
+            pub tags: &'static [&'static str],
+            /// The icon's codepoint in the icon font.
+            pub codepoint: Option<u32>,
+            /// The version the icon was first published in, as written in the
+            /// metadata, e.g. `"1.0"`.
+            pub published_in: Option<&'static str>,
+            /// The version the icon was last updated in, e.g. `"1.0"`.
+            pub updated_in: Option<&'static str>,
         }
 
-        /// The SVG path data for all styles of a particular icon.
+        /// The SVG path data for all styles of a particular icon, with the
+        /// `viewBox` each style is drawn in.
         #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-        pub struct IconStyleData([&'static str; #style_len]);
+        pub struct IconStyleData {
+            markup: [&'static str; #style_len],
+            view_boxes: [ViewBox; #style_len],
+            meta: &'static IconMeta,
+            #[cfg(feature = "nodes")]
+            nodes: [&'static [SvgNode]; #style_len],
+        }
 
         impl IconStyleData {
             pub fn get(&self, style: IconStyle) -> &'static str {
@@ -324,42 +795,264 @@ pub fn run() {
                     #(#style_indices),*
                 }
             }
+
+            /// The `viewBox` the given style is drawn in. Styles the icon isn't
+            /// drawn in have the `viewBox` of its first style.
+            pub fn view_box(&self, style: IconStyle) -> ViewBox {
+                match style {
+                    #(#view_box_indices),*
+                }
+            }
+
+            /// The markup of the given style as a tree of elements, which is
+            /// empty for styles the icon isn't drawn in.
+            #[cfg(feature = "nodes")]
+            pub fn nodes(&self, style: IconStyle) -> &'static [SvgNode] {
+                match style {
+                    #(#node_indices),*
+                }
+            }
+
+            /// Whether the icon is drawn in the given style.
+            pub fn has_style(&self, style: IconStyle) -> bool {
+                !self.get(style).is_empty()
+            }
+
+            /// The styles the icon is drawn in, in declaration order.
+            pub fn available_styles(&self) -> impl Iterator<Item = IconStyle> + '_ {
+                IconStyle::ALL.into_iter().filter(|&style| self.has_style(style))
+            }
+
+            /// The icon's metadata, such as its name, tags and categories.
+            pub const fn meta(&self) -> &'static IconMeta {
+                self.meta
+            }
         }
 
         pub type IconData = &'static IconStyleData;
 
+        /// The error returned when parsing an unknown icon name.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct ParseIconError(String);
+
+        impl core::fmt::Display for ParseIconError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "unknown icon `{}`", self.0)
+            }
+        }
+
+        impl core::error::Error for ParseIconError {}
+
+        impl core::str::FromStr for IconData {
+            type Err = ParseIconError;
+
+            /// Parses an icon from its kebab-case or PascalCase name, see [`icon_by_name`].
+            fn from_str(name: &str) -> Result<Self, Self::Err> {
+                icon_by_name(name).ok_or_else(|| ParseIconError(name.to_string()))
+            }
+        }
+    };
+
+    fs::write(format!("{CORE_DIR}/src/lib.rs"), core_lib.to_string())
+        .expect("Error writing core lib file");
+
+    // The elements the sanitizer lets through are the ones `nodes.rs` has to
+    // build, each with its `leptos::svg` constructor.
+    let svg_elements = sanitize::ELEMENTS.iter().map(|&name| {
+        let constructor = match name {
+            "use" => proc_macro2::Ident::new_raw(name, proc_macro2::Span::call_site()),
+            name => format_ident!("{name}"),
+        };
+        quote! { (#name, #constructor) }
+    });
+
+    let lib = quote! {
+        //! Phosphor is a flexible icon family for interfaces, diagrams,
+        //! presentations — whatever, really.
+        //! You can explore the available icons at [phosphoricons.com](https://phosphoricons.com).
+        //!
+        //! ```
+        //! use leptos::prelude::*;
+        //! use lumo_icons::{Icon, IconStyle, CLIPBOARD_LIST, COPY_BOLD, GEM_FILL};
+        //!
+        //! #[component]
+        //! fn MyComponent() -> impl IntoView {
+        //!     view! {
+        //!         <Icon icon=GEM_FILL style=IconStyle::MicroBold />
+        //!         <Icon icon=COPY_BOLD color="#AE2983" style=IconStyle::MicroBold size="32px" />
+        //!         <Icon icon=CLIPBOARD_LIST color="teal" style=IconStyle::Ui />
+        //!     }
+        //! }
+        //! ```
+        //!
+        //! Enable the `csr`, `hydrate` or `ssr` feature to match the rendering mode
+        //! of your Leptos application. The icon data itself lives in
+        //! [`lumo_icons_core`], which is re-exported here; turn off the default
+        //! `leptos` feature to use it without Leptos.
+        //!
+        //! The `dioxus` and `yew` features add `dioxus::Icon` and `yew::Icon`
+        //! components with the same props, for apps built with those frameworks.
+        //!
+        //! With the `trusted-types` feature, the Leptos components build the icons'
+        //! elements one by one instead of assigning `inner_html`.
+        #[cfg(feature = "leptos")]
+        use leptos::{attr::custom::custom_attribute, prelude::*, text_prop::TextProp};
+
+        pub use lumo_icons_core::*;
+
+        #[cfg(feature = "leptos")]
+        mod picker;
+        #[cfg(feature = "leptos")]
+        pub use picker::*;
+
+        #[cfg(feature = "leptos")]
+        mod sprite;
+        #[cfg(feature = "leptos")]
+        pub use sprite::*;
+
+        /// Calls the `elements!` macro in scope with every element the generator
+        /// lets through, as `(name, constructor)` pairs of the `leptos::svg` module.
+        #[cfg(all(feature = "leptos", feature = "trusted-types"))]
+        macro_rules! with_svg_elements {
+            () => {
+                elements!(#(#svg_elements),*)
+            };
+        }
+
+        #[cfg(all(feature = "leptos", feature = "trusted-types"))]
+        mod nodes;
+
+        #[cfg(feature = "dioxus")]
+        pub mod dioxus;
+
+        #[cfg(feature = "yew")]
+        pub mod yew;
+
+        #[cfg(any(feature = "leptos", feature = "dioxus", feature = "yew"))]
+        mod animation;
+
+        #[cfg(any(feature = "leptos", feature = "dioxus", feature = "yew"))]
+        mod diagnostics;
+
+        #[cfg(feature = "leptos")]
         #[component]
         pub fn Icon(
             icon: IconData,
             #[prop(into, default = Signal::stored(IconStyle::#default_variant))] style: Signal<IconStyle>,
             #[prop(into, default = TextProp::from("1em"))] size: TextProp,
+            #[prop(optional, into)] width: Option<TextProp>,
+            #[prop(optional, into)] height: Option<TextProp>,
+            #[prop(optional, into)] preserve_aspect_ratio: Option<TextProp>,
             #[prop(into, default = TextProp::from("currentColor"))] color: TextProp,
+            #[prop(optional, into)] secondary_color: Option<TextProp>,
+            #[prop(optional, into)] secondary_opacity: Option<Signal<f32>>,
             #[prop(into, default = Signal::stored(false))] mirrored: Signal<bool>,
+            #[prop(into, default = Signal::stored(false))] flip_vertical: Signal<bool>,
+            #[prop(into, default = Signal::stored(0.0))] rotate: Signal<f32>,
+            #[prop(optional, into)] transform: Option<TextProp>,
+            #[prop(into, default = Signal::stored(IconAnimation::None))] animation: Signal<IconAnimation>,
+            #[prop(optional, into)] animation_speed: Option<Signal<f32>>,
+            #[prop(optional, into)] animation_iterations: Option<Signal<u32>>,
+            #[prop(optional, into)] title: Option<TextProp>,
+            #[prop(optional)] fallback: StyleFallback,
+            #[prop(optional)] sprite: bool,
         ) -> impl IntoView {
-            let html = move || icon.get(style.get());
-            let transform = move || mirrored.get().then_some("scale(-1, 1)");
-            let height = size.clone();
+            let sprites = sprite.then(use_context::<sprite::SpriteRegistry>).flatten();
+            if sprite && sprites.is_none() {
+                diagnostics::warn_missing_sprite_sheet(icon);
+            }
+            let content = move || {
+                let style = style.get();
+                diagnostics::warn_missing_style(icon, style, fallback);
+                let transform = transform.as_ref().map(TextProp::get);
+                let transform = icon_transform(
+                    icon.view_box_or_fallback(style, fallback),
+                    rotate.get(),
+                    mirrored.get(),
+                    flip_vertical.get(),
+                    transform.as_deref(),
+                );
+                #[cfg(not(feature = "trusted-types"))]
+                let content = {
+                    let markup = match sprites {
+                        Some(sprites) => icon
+                            .resolve_style(style, fallback)
+                            .map(|style| sprites.use_markup(icon, style))
+                            .unwrap_or_default(),
+                        None => icon.get_or_fallback(style, fallback).to_string(),
+                    };
+                    transform_group(&markup, transform.as_deref())
+                };
+                #[cfg(feature = "trusted-types")]
+                let content = nodes::icon_view(icon, style, fallback, sprites, transform);
+                content
+            };
+            let view_box = move || icon.view_box_or_fallback(style.get(), fallback).to_string();
+            let dimensions = Signal::derive(move || {
+                let width = width.as_ref().map(TextProp::get);
+                let height = height.as_ref().map(TextProp::get);
+                icon_size(
+                    icon.view_box_or_fallback(style.get(), fallback),
+                    &size.get(),
+                    width.as_deref(),
+                    height.as_deref(),
+                )
+            });
+            let preserve_aspect_ratio = move || preserve_aspect_ratio.as_ref().map(TextProp::get);
             let color_attr = color.clone();
+            let labelled = title.is_some();
+            let label = move || title.as_ref().map(TextProp::get);
+            Effect::new(move |_| {
+                if animation.get() != IconAnimation::None {
+                    animation::inject_stylesheet();
+                }
+            });
+            let class = move || animation.get().class();
+            let icon_style = move || {
+                let color = secondary_color.as_ref().map(TextProp::get);
+                icon_style(
+                    color.as_deref(),
+                    secondary_opacity.map(|opacity| opacity.get()),
+                    animation_speed.map(|speed| speed.get()),
+                    animation_iterations.map(|iterations| iterations.get()),
+                )
+            };
 
-            view! {
+            let svg = view! {
                 <svg
                     xmlns="http://www.w3.org/2000/svg"
-                    width=move || size.get()
-                    height=move || height.get()
+                    role=icon_role(labelled)
+                    aria-hidden=icon_aria_hidden(labelled)
+                    aria-label=label
+                    width=move || dimensions.get().0
+                    height=move || dimensions.get().1
                     fill=move || color.get()
                     color=move || color_attr.get()
-                    transform=transform
-                    viewBox=concat!("0 0 ", #canvas_int, " ", #canvas_int)
-                    inner_html=html
+                    viewBox=view_box
+                    preserveAspectRatio=preserve_aspect_ratio
+                    style=icon_style
                 />
             }
+            .into_inner()
+            .add_any_attr(custom_attribute("class", class));
+
+            #[cfg(not(feature = "trusted-types"))]
+            let svg = svg.inner_html(content);
+            #[cfg(feature = "trusted-types")]
+            let svg = svg.child(content);
+            svg
         }
     };
 
     fs::write("src/lib.rs", lib.to_string()).expect("Error writing lib file");
 
-    // Write out the newly generated cargo file
+    // Write out the newly generated cargo files
     fs::write("Cargo.toml", cargo_template(&categories_set)).unwrap();
+    fs::write(
+        format!("{CORE_DIR}/Cargo.toml"),
+        core_cargo_template(&categories_set),
+    )
+    .unwrap();
 
     process::Command::new("cargo")
         .arg("fmt")
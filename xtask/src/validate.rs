@@ -0,0 +1,237 @@
+use crate::update::{extract_categories, extract_metadata, ASSETS_DIR, TYPESCRIPT_EXPORT_FILE};
+use convert_case::{Case, Casing};
+use std::collections::{BTreeMap, BTreeSet};
+use std::{fs, process};
+
+/// The sets icons can be published in. Each set is a Cargo feature of both
+/// crates, so adding one is a deliberate change to this list.
+pub(crate) const SETS: &[&str] = &["business-finance", "core", "flags", "micro-bold", "ui"];
+
+/// The styles each icon is drawn in, from the SVG files in the style folders.
+pub(crate) fn asset_styles() -> BTreeMap<String, BTreeSet<String>> {
+    let mut icons: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for style in fs::read_dir(ASSETS_DIR).unwrap().flatten() {
+        let Ok(style) = style.file_name().into_string() else {
+            continue;
+        };
+        let Ok(dir) = fs::read_dir(format!("{ASSETS_DIR}/{style}")) else {
+            continue;
+        };
+        for entry in dir.flatten() {
+            if let Some(icon_name) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_suffix(".svg"))
+            {
+                icons
+                    .entry(icon_name.to_string())
+                    .or_default()
+                    .insert(style.clone());
+            }
+        }
+    }
+    icons
+}
+
+fn list<'a>(items: impl IntoIterator<Item = &'a String>) -> String {
+    let items: Vec<_> = items.into_iter().map(String::as_str).collect();
+    format!("[{}]", items.join(", "))
+}
+
+/// Everything the metadata gets wrong about the icons in `assets`, which maps
+/// each icon to the styles it is drawn in.
+fn problems(typescript_export: &str, assets: &BTreeMap<String, BTreeSet<String>>) -> Vec<String> {
+    let (icon_sets, _) = extract_categories(typescript_export);
+    let icon_metadata = extract_metadata(typescript_export);
+
+    let mut problems = Vec::new();
+
+    for icon_name in assets.keys() {
+        if !icon_metadata.contains_key(icon_name) {
+            problems.push(format!(
+                "{icon_name}: has assets but no entry in {TYPESCRIPT_EXPORT_FILE}, so it would be uncategorized"
+            ));
+        }
+    }
+
+    let mut icon_names: Vec<_> = icon_metadata.keys().collect();
+    icon_names.sort_unstable();
+    let mut codepoints: BTreeMap<u32, Vec<&str>> = BTreeMap::new();
+    for icon_name in icon_names {
+        let metadata = &icon_metadata[icon_name];
+
+        match assets.get(icon_name) {
+            None => problems.push(format!(
+                "{icon_name}: is in {TYPESCRIPT_EXPORT_FILE} but has no SVG in {ASSETS_DIR}"
+            )),
+            Some(styles) => {
+                let declared: BTreeSet<String> = metadata.styles.iter().cloned().collect();
+                if &declared != styles {
+                    problems.push(format!(
+                        "{icon_name}: `styles` is {} but it is drawn in {}",
+                        list(&declared),
+                        list(styles)
+                    ));
+                }
+            }
+        }
+
+        let sets = icon_sets
+            .get(icon_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        if sets.is_empty() {
+            problems.push(format!("{icon_name}: isn't in any set"));
+        }
+        for set in sets {
+            if !SETS.contains(&set.as_str()) {
+                problems.push(format!(
+                    "{icon_name}: unknown set `{set}`, expected one of {}",
+                    SETS.join(", ")
+                ));
+            }
+        }
+
+        if let Some(pascal_name) = &metadata.pascal_name {
+            let expected = icon_name.to_case(Case::Pascal);
+            if *pascal_name != expected {
+                problems.push(format!(
+                    "{icon_name}: `pascal_name` is `{pascal_name}` but the name makes it `{expected}`"
+                ));
+            }
+        }
+
+        if let Some(codepoint) = metadata.codepoint {
+            codepoints.entry(codepoint).or_default().push(icon_name);
+        }
+    }
+
+    for (codepoint, icon_names) in codepoints {
+        if icon_names.len() > 1 {
+            problems.push(format!(
+                "codepoint {codepoint} (U+{codepoint:04X}) is used by {}",
+                icon_names.join(", ")
+            ));
+        }
+    }
+    problems
+}
+
+pub fn run() {
+    let typescript_export = fs::read_to_string(TYPESCRIPT_EXPORT_FILE).unwrap();
+    let assets = asset_styles();
+    let problems = problems(&typescript_export, &assets);
+
+    if problems.is_empty() {
+        println!(
+            "{TYPESCRIPT_EXPORT_FILE} agrees with the {} icons in {ASSETS_DIR}",
+            assets.len()
+        );
+        return;
+    }
+
+    for problem in &problems {
+        eprintln!("{problem}");
+    }
+    eprintln!(
+        "Found {} problem{}",
+        problems.len(),
+        if problems.len() == 1 { "" } else { "s" }
+    );
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Problems with the `arrow` icon, drawn in `micro-bold`, given the fields
+    /// of its metadata.
+    fn arrow_problems(fields: &str) -> Vec<String> {
+        let assets = BTreeMap::from([(
+            "arrow".to_string(),
+            BTreeSet::from(["micro-bold".to_string()]),
+        )]);
+        problems(&format!(r#"{{ "arrow": {{ {fields} }} }}"#), &assets)
+    }
+
+    const ARROW: &str = r#""name": "arrow", "pascal_name": "Arrow", "sets": ["ui"], "styles": ["micro-bold"], "codepoint": 71001"#;
+
+    #[test]
+    fn agrees_with_matching_metadata() {
+        assert_eq!(arrow_problems(ARROW), Vec::<String>::new());
+    }
+
+    #[test]
+    fn finds_assets_without_metadata() {
+        let assets = BTreeMap::from([("arrow".to_string(), BTreeSet::from(["ui".to_string()]))]);
+        let problems = problems("{}", &assets);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("arrow: has assets but no entry"));
+    }
+
+    #[test]
+    fn finds_metadata_without_assets() {
+        let problems = problems(
+            &format!(r#"{{ "arrow": {{ {ARROW} }} }}"#),
+            &BTreeMap::new(),
+        );
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("arrow: is in"));
+    }
+
+    #[test]
+    fn finds_styles_that_differ_from_the_assets() {
+        assert_eq!(
+            arrow_problems(&ARROW.replace(r#""styles": ["micro-bold"]"#, r#""styles": ["ui"]"#)),
+            ["arrow: `styles` is [ui] but it is drawn in [micro-bold]"]
+        );
+    }
+
+    #[test]
+    fn finds_unknown_sets() {
+        assert_eq!(
+            arrow_problems(&ARROW.replace(r#""sets": ["ui"]"#, r#""sets": ["ui", "arrows"]"#)),
+            [format!(
+                "arrow: unknown set `arrows`, expected one of {}",
+                SETS.join(", ")
+            )]
+        );
+        assert_eq!(
+            arrow_problems(&ARROW.replace(r#""sets": ["ui"]"#, r#""sets": []"#)),
+            ["arrow: isn't in any set"]
+        );
+    }
+
+    #[test]
+    fn finds_pascal_names_that_differ_from_the_name() {
+        assert_eq!(
+            arrow_problems(
+                &ARROW.replace(r#""pascal_name": "Arrow""#, r#""pascal_name": "ArrowIcon""#)
+            ),
+            ["arrow: `pascal_name` is `ArrowIcon` but the name makes it `Arrow`"]
+        );
+    }
+
+    #[test]
+    fn finds_duplicate_codepoints() {
+        let assets = BTreeMap::from([
+            (
+                "arrow".to_string(),
+                BTreeSet::from(["micro-bold".to_string()]),
+            ),
+            (
+                "bell".to_string(),
+                BTreeSet::from(["micro-bold".to_string()]),
+            ),
+        ]);
+        let bell = ARROW.replace("arrow", "bell").replace("Arrow", "Bell");
+        assert_eq!(
+            problems(
+                &format!(r#"{{ "arrow": {{ {ARROW} }}, "bell": {{ {bell} }} }}"#),
+                &assets
+            ),
+            ["codepoint 71001 (U+11559) is used by arrow, bell"]
+        );
+    }
+}